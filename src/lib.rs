@@ -14,6 +14,15 @@ use response::{
     player_response::PlayerResponse,
     punishment_stats_response::PunishmentStatsResponse,
     recent_games_response::RecentGamesResponse,
+    resources::{
+        achievements_response::AchievementsResponse, challenges_response::ChallengesResponse,
+        games_response::GamesResponse, guild_achievements_response::GuildAchievementsResponse,
+        quests_response::QuestsResponse, skyblock_bingo_response::SkyblockBingoResourceResponse,
+        skyblock_collections_response::SkyblockCollectionsResponse,
+        skyblock_election_response::SkyblockElectionResponse,
+        skyblock_items_response::SkyblockItemsResponse,
+        skyblock_skills_response::SkyblockSkillsResponse, vanity_response::VanityResponse,
+    },
     skyblock::{
         skyblock_auction_response::SkyblockAuctionResponse,
         skyblock_auctions_ended_response::SkyblockAuctionsEndedResponse,
@@ -92,7 +101,9 @@ impl RsPixel {
     where
         for<'a> T: DeserializeOwned + Send + Sync + 'a,
     {
-        let cache_key = format!("{}-{:?}", endpoint.0, params);
+        // The response type is part of the key, since the same endpoint can be cached both as
+        // `Value` by `get_resources` and as its typed response
+        let cache_key = format!("{}-{:?}-{}", endpoint.0, params, std::any::type_name::<T>());
 
         if let Some(cache) = &self.config.cache {
             if let Some(cached) = cache.get(&cache_key) {
@@ -336,6 +347,75 @@ impl RsPixel {
             self.simple_get(resource).await
         }
     }
+
+    pub async fn get_resources_games(&mut self) -> Result<Arc<GamesResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_GAMES).await
+    }
+
+    pub async fn get_resources_achievements(&mut self) -> Result<Arc<AchievementsResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_ACHIEVEMENTS)
+            .await
+    }
+
+    pub async fn get_resources_challenges(&mut self) -> Result<Arc<ChallengesResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_CHALLENGES).await
+    }
+
+    pub async fn get_resources_quests(&mut self) -> Result<Arc<QuestsResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_QUESTS).await
+    }
+
+    pub async fn get_resources_guild_achievements(
+        &mut self,
+    ) -> Result<Arc<GuildAchievementsResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_GUILD_ACHIEVEMENTS)
+            .await
+    }
+
+    pub async fn get_resources_vanity_pets(&mut self) -> Result<Arc<VanityResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_VANITY_PETS)
+            .await
+    }
+
+    pub async fn get_resources_vanity_companions(&mut self) -> Result<Arc<VanityResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_VANITY_COMPANIONS)
+            .await
+    }
+
+    pub async fn get_resources_skyblock_collections(
+        &mut self,
+    ) -> Result<Arc<SkyblockCollectionsResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_SKYBLOCK_COLLECTIONS)
+            .await
+    }
+
+    pub async fn get_resources_skyblock_skills(
+        &mut self,
+    ) -> Result<Arc<SkyblockSkillsResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_SKYBLOCK_SKILLS)
+            .await
+    }
+
+    pub async fn get_resources_skyblock_items(
+        &mut self,
+    ) -> Result<Arc<SkyblockItemsResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_SKYBLOCK_ITEMS)
+            .await
+    }
+
+    pub async fn get_resources_skyblock_election(
+        &mut self,
+    ) -> Result<Arc<SkyblockElectionResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_SKYBLOCK_ELECTION)
+            .await
+    }
+
+    pub async fn get_resources_skyblock_bingo(
+        &mut self,
+    ) -> Result<Arc<SkyblockBingoResourceResponse>, Error> {
+        self.simple_get(HypixelEndpoint::RESOURCES_SKYBLOCK_BINGO)
+            .await
+    }
 }
 
 #[derive(Eq, Hash, PartialEq)]
@@ -426,12 +506,14 @@ pub enum RateLimitStrategy {
     Error,
 }
 
+pub type CacheEntry = (Duration, Arc<dyn Any + Send + Sync>);
+
 pub struct Config {
     pub client: Client,
     pub minecraft_api_type: ApiType,
     pub rate_limit_strategy: RateLimitStrategy,
     pub uuid_to_username_cache: Option<Cache<String, String>>,
-    pub cache: Option<Cache<String, (Duration, Arc<dyn Any + Send + Sync>)>>,
+    pub cache: Option<Cache<String, CacheEntry>>,
     pub hypixel_cache_ttls: HashMap<HypixelEndpoint, Duration>,
 }
 
@@ -501,11 +583,11 @@ impl From<ConfigBuilder> for Config {
 
 struct HypixelCacheExpiry;
 
-impl Expiry<String, (Duration, Arc<dyn Any + Send + Sync>)> for HypixelCacheExpiry {
+impl Expiry<String, CacheEntry> for HypixelCacheExpiry {
     fn expire_after_create(
         &self,
         _key: &String,
-        value: &(Duration, Arc<dyn Any + Send + Sync>),
+        value: &CacheEntry,
         _current_time: Instant,
     ) -> Option<Duration> {
         Some(value.0)
@@ -1,4 +1,4 @@
-use crate::types::game_type::GameType;
+use crate::{types::game_type::GameType, util::formatting::ChatColor};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 
@@ -39,6 +39,23 @@ pub struct Guild {
     pub legacy_ranking: Option<i64>,
 }
 
+impl Guild {
+    pub fn get_tag_color(&self) -> Option<ChatColor> {
+        ChatColor::from_name(&self.tag_color)
+    }
+
+    /// The guild tag with its color code, such as `§6[TAG]`
+    pub fn get_formatted_tag(&self) -> String {
+        format!(
+            "{}[{}]",
+            self.get_tag_color()
+                .map(|color| color.get_formatting_code())
+                .unwrap_or_default(),
+            self.tag
+        )
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Member {
     pub uuid: String,
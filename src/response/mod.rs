@@ -6,5 +6,6 @@ pub mod leaderboards_response;
 pub mod player_response;
 pub mod punishment_stats_response;
 pub mod recent_games_response;
+pub mod resources;
 pub mod skyblock;
 pub mod status_response;
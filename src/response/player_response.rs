@@ -1,4 +1,5 @@
 use crate::util::{
+    formatting::ChatColor,
     generic_json::{Property, Raw},
     utils,
 };
@@ -32,11 +33,9 @@ impl PlayerResponse {
                     .and_then(serde_json::Value::as_str)
                 {
                     Some(alias) => Some(alias),
-                    None => {
-                        return self
-                            .get_str_property("playername")
-                            .or_else(|| self.get_str_property("username"))
-                    }
+                    None => self
+                        .get_str_property("playername")
+                        .or_else(|| self.get_str_property("username")),
                 }
             }
         }
@@ -77,6 +76,52 @@ impl PlayerResponse {
         self.get_rank() != "NONE"
     }
 
+    /// Get the color of the rank, or `None` if the player has no rank
+    pub fn get_rank_color(&self) -> Option<ChatColor> {
+        Some(match self.get_rank() {
+            "ADMIN" | "OWNER" | "YOUTUBER" => ChatColor::Red,
+            "GAME_MASTER" | "MODERATOR" => ChatColor::DarkGreen,
+            "HELPER" => ChatColor::Blue,
+            "SUPERSTAR" => self
+                .get_str_property("monthlyRankColor")
+                .and_then(ChatColor::from_name)
+                .unwrap_or(ChatColor::Gold),
+            "MVP_PLUS" | "MVP" => ChatColor::Aqua,
+            "VIP_PLUS" | "VIP" => ChatColor::Green,
+            _ => return None,
+        })
+    }
+
+    /// Get the rank prefix with formatting codes as shown in chat (e.g. `§b[MVP§c+§b]`). Custom
+    /// prefixes are returned as is.
+    pub fn get_rank_prefix(&self) -> Option<String> {
+        if let Some(prefix) = self.get_str_property("prefix") {
+            return Some(prefix.to_string());
+        }
+
+        let color = self.get_rank_color()?.get_formatting_code();
+        let plus_color = self
+            .get_str_property("rankPlusColor")
+            .and_then(ChatColor::from_name)
+            .unwrap_or(ChatColor::Red)
+            .get_formatting_code();
+
+        Some(match self.get_rank() {
+            "ADMIN" => format!("{color}[ADMIN]"),
+            "OWNER" => format!("{color}[OWNER]"),
+            "YOUTUBER" => format!("{color}[§fYOUTUBE{color}]"),
+            "GAME_MASTER" => format!("{color}[GM]"),
+            "MODERATOR" => format!("{color}[MOD]"),
+            "HELPER" => format!("{color}[HELPER]"),
+            "SUPERSTAR" => format!("{color}[MVP{plus_color}++{color}]"),
+            "MVP_PLUS" => format!("{color}[MVP{plus_color}+{color}]"),
+            "MVP" => format!("{color}[MVP]"),
+            "VIP_PLUS" => format!("{color}[VIP§6+{color}]"),
+            "VIP" => format!("{color}[VIP]"),
+            _ => return None,
+        })
+    }
+
     fn has_rank_in_field(&self, name: &str) -> bool {
         let value = self.get_str_property(name).unwrap_or("NONE");
         !value.is_empty() && value != "NONE" && value != "NORMAL"
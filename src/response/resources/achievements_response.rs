@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AchievementsResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    /// Achievements keyed by game (e.g. `bedwars`)
+    pub achievements: HashMap<String, GameAchievements>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GameAchievements {
+    #[serde(default)]
+    pub one_time: HashMap<String, OneTimeAchievement>,
+    #[serde(default)]
+    pub tiered: HashMap<String, TieredAchievement>,
+    #[serde(default)]
+    pub total_points: i64,
+    #[serde(default)]
+    pub total_legacy_points: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OneTimeAchievement {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub points: i64,
+    #[serde(rename = "gamePercentUnlocked")]
+    pub game_percent_unlocked: Option<f64>,
+    #[serde(rename = "globalPercentUnlocked")]
+    pub global_percent_unlocked: Option<f64>,
+    #[serde(default)]
+    pub legacy: bool,
+    #[serde(default)]
+    pub secret: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TieredAchievement {
+    pub name: String,
+    /// Contains `%s` where the amount of the tier goes
+    pub description: String,
+    #[serde(default)]
+    pub tiers: Vec<AchievementTier>,
+    #[serde(default)]
+    pub legacy: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AchievementTier {
+    pub tier: i64,
+    #[serde(default)]
+    pub points: i64,
+    pub amount: i64,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChallengesResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    /// Challenges keyed by game type (e.g. `ARCADE`)
+    pub challenges: HashMap<String, Vec<Challenge>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Challenge {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub rewards: Vec<Reward>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Reward {
+    #[serde(rename = "type")]
+    pub reward_type: String,
+    #[serde(default)]
+    pub amount: i64,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GamesResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    /// Games keyed by type name (e.g. `BEDWARS`)
+    pub games: HashMap<String, GameResource>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GameResource {
+    pub id: i64,
+    pub name: String,
+    /// Missing for some entries
+    #[serde(rename = "databaseName")]
+    pub database_name: Option<String>,
+    /// Display names keyed by mode id
+    #[serde(rename = "modeNames", default)]
+    pub mode_names: HashMap<String, String>,
+    #[serde(default)]
+    pub retired: bool,
+    #[serde(default)]
+    pub legacy: bool,
+}
@@ -0,0 +1,23 @@
+use super::achievements_response::AchievementTier;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GuildAchievementsResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    #[serde(default)]
+    pub one_time: HashMap<String, GuildAchievement>,
+    #[serde(default)]
+    pub tiered: HashMap<String, GuildAchievement>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GuildAchievement {
+    pub name: String,
+    pub description: String,
+    /// Empty for one time achievements
+    #[serde(default)]
+    pub tiers: Vec<AchievementTier>,
+}
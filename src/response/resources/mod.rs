@@ -0,0 +1,11 @@
+pub mod achievements_response;
+pub mod challenges_response;
+pub mod games_response;
+pub mod guild_achievements_response;
+pub mod quests_response;
+pub mod skyblock_bingo_response;
+pub mod skyblock_collections_response;
+pub mod skyblock_election_response;
+pub mod skyblock_items_response;
+pub mod skyblock_skills_response;
+pub mod vanity_response;
@@ -0,0 +1,45 @@
+use super::challenges_response::Reward;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuestsResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    /// Quests keyed by game (e.g. `bedwars`)
+    pub quests: HashMap<String, Vec<Quest>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Quest {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub rewards: Vec<Reward>,
+    #[serde(default)]
+    pub objectives: Vec<QuestObjective>,
+    #[serde(default)]
+    pub requirements: Vec<QuestRequirement>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuestObjective {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub objective_type: String,
+    /// Amount needed by `IntegerObjective`s
+    pub integer: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuestRequirement {
+    /// Such as `DailyResetQuestRequirement`
+    #[serde(rename = "type")]
+    pub requirement_type: String,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
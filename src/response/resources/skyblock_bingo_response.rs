@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SkyblockBingoResourceResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    pub id: i64,
+    pub name: String,
+    pub start: i64,
+    pub end: i64,
+    /// Such as `NORMAL` or `EXTREME`, missing for older events
+    pub modifier: Option<String>,
+    pub goals: Vec<BingoGoal>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BingoGoal {
+    pub id: String,
+    pub name: String,
+    /// Missing for community goals
+    pub lore: Option<String>,
+    #[serde(rename = "fullLore", default)]
+    pub full_lore: Vec<String>,
+    /// Amounts for each tier of community goals
+    #[serde(default)]
+    pub tiers: Vec<i64>,
+    /// Community progress
+    pub progress: Option<i64>,
+    #[serde(rename = "requiredAmount")]
+    pub required_amount: Option<i64>,
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SkyblockCollectionsResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    pub version: String,
+    /// Categories keyed by id (e.g. `FARMING`)
+    pub collections: HashMap<String, CollectionCategory>,
+}
+
+impl SkyblockCollectionsResponse {
+    /// Get a collection and the id of its category
+    pub fn get_collection(&self, id: &str) -> Option<(&str, &CollectionItem)> {
+        self.collections.iter().find_map(|(category_id, category)| {
+            category
+                .items
+                .get(id)
+                .map(|item| (category_id.as_str(), item))
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CollectionCategory {
+    pub name: String,
+    /// Collections keyed by item id (e.g. `WHEAT`)
+    pub items: HashMap<String, CollectionItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CollectionItem {
+    pub name: String,
+    #[serde(rename = "maxTiers")]
+    pub max_tiers: i64,
+    pub tiers: Vec<CollectionTier>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CollectionTier {
+    pub tier: i64,
+    #[serde(rename = "amountRequired")]
+    pub amount_required: i64,
+    pub unlocks: Vec<String>,
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SkyblockElectionResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    pub mayor: Mayor,
+    /// The election in progress, missing while votes are closed
+    pub current: Option<Election>,
+}
+
+impl SkyblockElectionResponse {
+    /// Get the perks of the mayor and the minister
+    pub fn get_active_perks(&self) -> Vec<&Perk> {
+        self.mayor
+            .perks
+            .iter()
+            .chain(self.mayor.minister.as_ref().map(|minister| &minister.perk))
+            .collect()
+    }
+
+    /// Whether the mayor or the minister has a perk (e.g. `Marauder`), ignoring case
+    pub fn is_perk_active(&self, name: &str) -> bool {
+        self.get_active_perks()
+            .iter()
+            .any(|perk| perk.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Whether the mayor or the minister is a candidate, by key (e.g. `fishing`) or name (e.g.
+    /// `Marina`) ignoring case
+    pub fn is_mayor_active(&self, key_or_name: &str) -> bool {
+        let matches = |key: &str, name: &str| {
+            key.eq_ignore_ascii_case(key_or_name) || name.eq_ignore_ascii_case(key_or_name)
+        };
+        matches(&self.mayor.key, &self.mayor.name)
+            || self
+                .mayor
+                .minister
+                .as_ref()
+                .is_some_and(|minister| matches(&minister.key, &minister.name))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Mayor {
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub perks: Vec<Perk>,
+    pub minister: Option<Minister>,
+    /// The election the mayor won, missing for special mayors
+    pub election: Option<Election>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Minister {
+    pub key: String,
+    pub name: String,
+    pub perk: Perk,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Perk {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Whether the perk can be a minister perk
+    #[serde(default)]
+    pub minister: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Election {
+    pub year: i64,
+    pub candidates: Vec<Candidate>,
+}
+
+impl Election {
+    pub fn get_total_votes(&self) -> i64 {
+        self.candidates
+            .iter()
+            .map(|candidate| candidate.votes)
+            .sum()
+    }
+
+    /// Get the share of votes of each candidate as a percentage, most votes first
+    pub fn get_vote_shares(&self) -> Vec<(&Candidate, f64)> {
+        let total_votes = self.get_total_votes();
+        let mut shares = self
+            .candidates
+            .iter()
+            .map(|candidate| {
+                let share = if total_votes > 0 {
+                    candidate.votes as f64 / total_votes as f64 * 100.0
+                } else {
+                    0.0
+                };
+                (candidate, share)
+            })
+            .collect::<Vec<_>>();
+        shares.sort_by_key(|(candidate, _)| Reverse(candidate.votes));
+        shares
+    }
+
+    /// Get the candidate with the most votes
+    pub fn get_leader(&self) -> Option<&Candidate> {
+        self.candidates
+            .iter()
+            .max_by_key(|candidate| candidate.votes)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Candidate {
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub perks: Vec<Perk>,
+    /// Missing before votes are shown
+    #[serde(default)]
+    pub votes: i64,
+}
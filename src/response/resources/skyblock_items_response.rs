@@ -0,0 +1,107 @@
+use crate::{types::rarity::Rarity, util::formatting::to_plain};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SkyblockItemsResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    pub items: Vec<SkyblockItemResource>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SkyblockItemResource {
+    pub id: String,
+    pub material: String,
+    /// May contain formatting codes
+    pub name: String,
+    pub durability: Option<i64>,
+    pub tier: Option<Rarity>,
+    /// Such as `SWORD` or `ACCESSORY`
+    pub category: Option<String>,
+    pub npc_sell_price: Option<f64>,
+    /// `SOLO` or `COOP`
+    pub soulbound: Option<String>,
+    #[serde(default)]
+    pub museum: bool,
+    #[serde(default)]
+    pub dungeon_item: bool,
+    #[serde(default)]
+    pub stats: HashMap<String, f64>,
+    #[serde(default)]
+    pub requirements: Vec<ItemRequirement>,
+    #[serde(default)]
+    pub catacombs_requirements: Vec<ItemRequirement>,
+    /// Essence and items for each dungeon star
+    #[serde(default)]
+    pub upgrade_costs: Vec<Vec<ItemUpgradeCost>>,
+    #[serde(default)]
+    pub gemstone_slots: Vec<ItemGemstoneSlot>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl SkyblockItemResource {
+    /// Get the name without formatting codes
+    pub fn get_plain_name(&self) -> String {
+        to_plain(&self.name)
+    }
+
+    pub fn is_soulbound(&self) -> bool {
+        self.soulbound.is_some()
+    }
+
+    /// Get the dungeon type and level needed to use the item, if any
+    pub fn get_dungeon_requirement(&self) -> Option<(&str, i64)> {
+        self.requirements
+            .iter()
+            .chain(&self.catacombs_requirements)
+            .find(|requirement| requirement.requirement_type == "DUNGEON_SKILL")
+            .and_then(|requirement| {
+                Some((requirement.dungeon_type.as_deref()?, requirement.level?))
+            })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ItemRequirement {
+    /// Such as `SKILL`, `SLAYER` or `DUNGEON_SKILL`
+    #[serde(rename = "type")]
+    pub requirement_type: String,
+    pub skill: Option<String>,
+    pub dungeon_type: Option<String>,
+    pub slayer_boss_type: Option<String>,
+    pub level: Option<i64>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ItemUpgradeCost {
+    /// `ESSENCE` or `ITEM`
+    #[serde(rename = "type")]
+    pub cost_type: String,
+    pub essence_type: Option<String>,
+    pub item_id: Option<String>,
+    pub amount: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ItemGemstoneSlot {
+    pub slot_type: String,
+    /// Missing for slots that start unlocked
+    #[serde(default)]
+    pub costs: Vec<ItemGemstoneSlotCost>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ItemGemstoneSlotCost {
+    /// `COINS` or `ITEM`
+    #[serde(rename = "type")]
+    pub cost_type: String,
+    pub item_id: Option<String>,
+    pub amount: Option<i64>,
+    pub coins: Option<i64>,
+}
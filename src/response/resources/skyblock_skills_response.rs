@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SkyblockSkillsResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    pub version: Option<String>,
+    /// Skills keyed by id (e.g. `FARMING`)
+    pub skills: HashMap<String, SkillResource>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SkillResource {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(rename = "maxLevel")]
+    pub max_level: i64,
+    pub levels: Vec<SkillLevel>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SkillLevel {
+    pub level: i64,
+    #[serde(rename = "totalExpRequired")]
+    pub total_exp_required: f64,
+    #[serde(default)]
+    pub unlocks: Vec<String>,
+}
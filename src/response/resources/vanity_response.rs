@@ -0,0 +1,27 @@
+use crate::types::rarity::Rarity;
+use serde::{Deserialize, Serialize};
+
+/// Vanity pets or companions
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VanityResponse {
+    pub success: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: i64,
+    pub types: Vec<VanityType>,
+    pub rarities: Vec<VanityRarity>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VanityType {
+    pub key: String,
+    pub name: Option<String>,
+    pub rarity: Option<Rarity>,
+    /// Package that unlocks the pet or companion, missing for some
+    pub package: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VanityRarity {
+    pub name: Rarity,
+    pub color: String,
+}
@@ -1,6 +1,5 @@
-use crate::util::utils::parse_nbt;
+use crate::util::{skyblock_item::SkyblockItem, utils::parse_items};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SkyblockAuctionsEndedResponse {
@@ -23,7 +22,14 @@ pub struct SkyblockAuctionEnded {
 }
 
 impl SkyblockAuctionEnded {
-    pub fn get_nbt(&self) -> Option<Value> {
-        parse_nbt(&self.item_bytes)
+    pub fn get_nbt(&self) -> Option<SkyblockItem> {
+        parse_items(&self.item_bytes)
+            .ok()
+            .and_then(|items| items.into_iter().next().flatten())
+    }
+
+    /// Canonical id of the sold item, see [`SkyblockItem::get_canonical_id`]
+    pub fn get_item_id(&self) -> Option<String> {
+        self.get_nbt().and_then(|item| item.get_canonical_id())
     }
 }
@@ -1,4 +1,10 @@
-use crate::util::utils::parse_nbt;
+use crate::{
+    types::{auction_category::AuctionCategory, rarity::Rarity},
+    util::{
+        skyblock_item::SkyblockItem,
+        utils::{parse_items, strip_formatting},
+    },
+};
 use serde::{de, Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::fmt;
@@ -16,7 +22,7 @@ pub struct SkyblockAuctionsResponse {
     pub auctions: Vec<SkyblockAuction>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SkyblockAuction {
     pub uuid: String,
     pub auctioneer: String,
@@ -27,8 +33,8 @@ pub struct SkyblockAuction {
     pub item_name: String,
     pub item_lore: String,
     pub extra: String,
-    pub category: String,
-    pub tier: String,
+    pub category: AuctionCategory,
+    pub tier: Rarity,
     pub starting_bid: i64,
     #[serde(deserialize_with = "deserialize_item_bytes")]
     pub item_bytes: String,
@@ -41,8 +47,34 @@ pub struct SkyblockAuction {
 }
 
 impl SkyblockAuction {
-    pub fn get_nbt(&self) -> Option<Value> {
-        parse_nbt(&self.item_bytes)
+    pub fn get_nbt(&self) -> Option<SkyblockItem> {
+        parse_items(&self.item_bytes)
+            .ok()
+            .and_then(|items| items.into_iter().next().flatten())
+    }
+
+    pub fn get_highest_bid(&self) -> Option<&SkyblockAuctionBid> {
+        self.bids.iter().max_by_key(|bid| bid.amount)
+    }
+
+    /// The highest bid, or the starting bid if there are no bids
+    pub fn get_current_price(&self) -> i64 {
+        self.get_highest_bid()
+            .map_or(self.starting_bid, |bid| bid.amount)
+    }
+
+    pub fn is_ended(&self, now: i64) -> bool {
+        self.end <= now
+    }
+
+    /// Milliseconds until the auction ends, or zero if it has ended
+    pub fn get_time_remaining(&self, now: i64) -> i64 {
+        (self.end - now).max(0)
+    }
+
+    /// Lore lines with formatting codes removed
+    pub fn get_lore(&self) -> Vec<String> {
+        self.item_lore.lines().map(strip_formatting).collect()
     }
 }
 
@@ -94,7 +126,7 @@ where
     deserializer.deserialize_any(DeserializeItemBytesVisitor)
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SkyblockAuctionBid {
     pub bidder: String,
     pub profile_id: String,
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum AuctionCategory {
+    Weapon,
+    Armor,
+    Accessories,
+    Consumables,
+    Blocks,
+    Misc,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<&str> for AuctionCategory {
+    fn from(v: &str) -> Self {
+        match v.to_lowercase().as_str() {
+            "weapon" => AuctionCategory::Weapon,
+            "armor" => AuctionCategory::Armor,
+            "accessories" => AuctionCategory::Accessories,
+            "consumables" => AuctionCategory::Consumables,
+            "blocks" => AuctionCategory::Blocks,
+            "misc" => AuctionCategory::Misc,
+            _ => AuctionCategory::Unknown,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum DungeonClass {
+    Healer,
+    Mage,
+    Berserk,
+    Archer,
+    Tank,
+}
+
+impl DungeonClass {
+    pub const ALL: [DungeonClass; 5] = [
+        DungeonClass::Healer,
+        DungeonClass::Mage,
+        DungeonClass::Berserk,
+        DungeonClass::Archer,
+        DungeonClass::Tank,
+    ];
+
+    /// Get the class from its name (e.g. `mage`)
+    pub fn from_name(name: &str) -> Option<DungeonClass> {
+        DungeonClass::ALL
+            .into_iter()
+            .find(|class| class.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Get the name used by the API
+    pub fn name(&self) -> &'static str {
+        match self {
+            DungeonClass::Healer => "healer",
+            DungeonClass::Mage => "mage",
+            DungeonClass::Berserk => "berserk",
+            DungeonClass::Archer => "archer",
+            DungeonClass::Tank => "tank",
+        }
+    }
+}
+
+impl fmt::Display for DungeonClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -1,4 +1,9 @@
+pub mod auction_category;
+pub mod dungeon_class;
 pub mod game_type;
 pub mod gamemode;
 pub mod lobby_type;
+pub mod rarity;
 pub mod server_type;
+pub mod skill;
+pub mod slayer;
@@ -0,0 +1,95 @@
+use crate::util::formatting::ChatColor;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+    Mythic,
+    Divine,
+    Special,
+    VerySpecial,
+    Ultimate,
+    Admin,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<&str> for Rarity {
+    fn from(v: &str) -> Self {
+        match v.to_uppercase().replace(' ', "_").as_str() {
+            "COMMON" => Rarity::Common,
+            "UNCOMMON" => Rarity::Uncommon,
+            "RARE" => Rarity::Rare,
+            "EPIC" => Rarity::Epic,
+            "LEGENDARY" => Rarity::Legendary,
+            "MYTHIC" => Rarity::Mythic,
+            "DIVINE" => Rarity::Divine,
+            "SPECIAL" => Rarity::Special,
+            "VERY_SPECIAL" => Rarity::VerySpecial,
+            "ULTIMATE" => Rarity::Ultimate,
+            "ADMIN" => Rarity::Admin,
+            _ => Rarity::Unknown,
+        }
+    }
+}
+
+impl Rarity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rarity::Common => "COMMON",
+            Rarity::Uncommon => "UNCOMMON",
+            Rarity::Rare => "RARE",
+            Rarity::Epic => "EPIC",
+            Rarity::Legendary => "LEGENDARY",
+            Rarity::Mythic => "MYTHIC",
+            Rarity::Divine => "DIVINE",
+            Rarity::Special => "SPECIAL",
+            Rarity::VerySpecial => "VERY_SPECIAL",
+            Rarity::Ultimate => "ULTIMATE",
+            Rarity::Admin => "ADMIN",
+            Rarity::Unknown => "UNKNOWN",
+        }
+    }
+
+    /// Get the next rarity, such as from a recombobulator. The highest rarities stay the same.
+    pub fn get_upgraded(&self) -> Rarity {
+        match self {
+            Rarity::Common => Rarity::Uncommon,
+            Rarity::Uncommon => Rarity::Rare,
+            Rarity::Rare => Rarity::Epic,
+            Rarity::Epic => Rarity::Legendary,
+            Rarity::Legendary => Rarity::Mythic,
+            Rarity::Mythic => Rarity::Divine,
+            Rarity::Divine => Rarity::Special,
+            Rarity::Special => Rarity::VerySpecial,
+            other => *other,
+        }
+    }
+
+    pub fn get_color(&self) -> ChatColor {
+        match self {
+            Rarity::Common => ChatColor::White,
+            Rarity::Uncommon => ChatColor::Green,
+            Rarity::Rare => ChatColor::Blue,
+            Rarity::Epic => ChatColor::DarkPurple,
+            Rarity::Legendary => ChatColor::Gold,
+            Rarity::Mythic => ChatColor::LightPurple,
+            Rarity::Divine => ChatColor::Aqua,
+            Rarity::Special | Rarity::VerySpecial => ChatColor::Red,
+            Rarity::Ultimate | Rarity::Admin => ChatColor::DarkRed,
+            Rarity::Unknown => ChatColor::Gray,
+        }
+    }
+}
+
+impl fmt::Display for Rarity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
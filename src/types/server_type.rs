@@ -48,30 +48,14 @@ impl<'de> Deserialize<'de> for ServerType {
             where
                 E: de::Error,
             {
-                if let Ok(game_type) = GameType::try_from(value.clone()) {
-                    return Ok(ServerType::GameType(game_type));
-                }
-
-                if let Ok(lobby_type) = LobbyType::try_from(value) {
-                    return Ok(ServerType::LobbyType(lobby_type));
-                }
-
-                Ok(ServerType::Unknown)
+                self.visit_str(&value)
             }
 
             fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                if let Ok(game_type) = GameType::try_from(value.to_string()) {
-                    return Ok(ServerType::GameType(game_type));
-                }
-
-                if let Ok(lobby_type) = LobbyType::try_from(value.to_string()) {
-                    return Ok(ServerType::LobbyType(lobby_type));
-                }
-
-                Ok(ServerType::Unknown)
+                Ok(ServerType::GameType(GameType::from(value.to_string())))
             }
         }
 
@@ -0,0 +1,91 @@
+use crate::util::leveling_tables::LevelingTables;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Skill {
+    Farming,
+    Mining,
+    Combat,
+    Foraging,
+    Fishing,
+    Enchanting,
+    Alchemy,
+    Taming,
+    Carpentry,
+    Runecrafting,
+    Social,
+}
+
+impl Skill {
+    pub const ALL: [Skill; 11] = [
+        Skill::Farming,
+        Skill::Mining,
+        Skill::Combat,
+        Skill::Foraging,
+        Skill::Fishing,
+        Skill::Enchanting,
+        Skill::Alchemy,
+        Skill::Taming,
+        Skill::Carpentry,
+        Skill::Runecrafting,
+        Skill::Social,
+    ];
+
+    /// Skills excluded from the usual skill average
+    pub const AVERAGE_EXCLUDED: [Skill; 3] = [Skill::Runecrafting, Skill::Social, Skill::Carpentry];
+
+    /// Get the skill from its name (e.g. `combat`)
+    pub fn from_name(name: &str) -> Option<Skill> {
+        Skill::ALL
+            .into_iter()
+            .find(|skill| skill.name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Skill::Farming => "farming",
+            Skill::Mining => "mining",
+            Skill::Combat => "combat",
+            Skill::Foraging => "foraging",
+            Skill::Fishing => "fishing",
+            Skill::Enchanting => "enchanting",
+            Skill::Alchemy => "alchemy",
+            Skill::Taming => "taming",
+            Skill::Carpentry => "carpentry",
+            Skill::Runecrafting => "runecrafting",
+            Skill::Social => "social",
+        }
+    }
+
+    /// Get the member key holding the skill experience in the legacy profile layout
+    pub fn get_exp_key(&self) -> String {
+        match self {
+            Skill::Social => "experience_skill_social2".to_string(),
+            _ => format!("experience_skill_{}", self.name()),
+        }
+    }
+
+    /// Get the experience needed for each level from the current [`LevelingTables`]
+    pub fn get_leveling_table(&self) -> Vec<i64> {
+        LevelingTables::current().get_skill_table(*self).to_vec()
+    }
+
+    /// Get the max level from the current [`LevelingTables`], not including the farming level cap
+    /// upgrade
+    pub fn get_max_level(&self) -> i64 {
+        LevelingTables::current().get_skill_max_level(*self)
+    }
+
+    /// Whether the skill doesn't give stats
+    pub fn is_cosmetic(&self) -> bool {
+        matches!(self, Skill::Runecrafting | Skill::Social)
+    }
+}
+
+impl fmt::Display for Skill {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
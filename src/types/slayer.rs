@@ -0,0 +1,90 @@
+use crate::util::{
+    constants::{BLAZE_SLAYER_SPAWN_COSTS, SLAYER_SPAWN_COSTS},
+    leveling_tables::LevelingTables,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Slayer {
+    Zombie,
+    Spider,
+    Wolf,
+    Enderman,
+    Blaze,
+    Vampire,
+}
+
+impl Slayer {
+    pub const ALL: [Slayer; 6] = [
+        Slayer::Zombie,
+        Slayer::Spider,
+        Slayer::Wolf,
+        Slayer::Enderman,
+        Slayer::Blaze,
+        Slayer::Vampire,
+    ];
+
+    /// Get the slayer from its name (e.g. `enderman`)
+    pub fn from_name(name: &str) -> Option<Slayer> {
+        Slayer::ALL
+            .into_iter()
+            .find(|slayer| slayer.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Get the name used by the API
+    pub fn name(&self) -> &'static str {
+        match self {
+            Slayer::Zombie => "zombie",
+            Slayer::Spider => "spider",
+            Slayer::Wolf => "wolf",
+            Slayer::Enderman => "enderman",
+            Slayer::Blaze => "blaze",
+            Slayer::Vampire => "vampire",
+        }
+    }
+
+    pub fn get_boss_name(&self) -> &'static str {
+        match self {
+            Slayer::Zombie => "Revenant Horror",
+            Slayer::Spider => "Tarantula Broodfather",
+            Slayer::Wolf => "Sven Packmaster",
+            Slayer::Enderman => "Voidgloom Seraph",
+            Slayer::Blaze => "Inferno Demonlord",
+            Slayer::Vampire => "Riftstalker Bloodfiend",
+        }
+    }
+
+    /// Get the total experience needed for each level from the current [`LevelingTables`]
+    pub fn get_leveling_table(&self) -> Vec<i64> {
+        LevelingTables::current().get_slayer_table(*self).to_vec()
+    }
+
+    /// Get the max level from the current [`LevelingTables`]
+    pub fn get_max_level(&self) -> i64 {
+        LevelingTables::current().get_slayer_max_level(*self)
+    }
+
+    pub fn get_max_tier(&self) -> usize {
+        match self {
+            Slayer::Zombie | Slayer::Spider | Slayer::Vampire => 5,
+            Slayer::Wolf | Slayer::Enderman | Slayer::Blaze => 4,
+        }
+    }
+
+    /// Get the coins to spawn each tier. Vampire is paid for in motes so has no coin cost.
+    pub fn get_spawn_costs(&self) -> &'static [i64] {
+        match self {
+            Slayer::Blaze => &BLAZE_SLAYER_SPAWN_COSTS,
+            Slayer::Vampire => &[],
+            _ => &SLAYER_SPAWN_COSTS[..self.get_max_tier()],
+        }
+    }
+}
+
+impl fmt::Display for Slayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
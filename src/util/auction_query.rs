@@ -0,0 +1,234 @@
+use super::{
+    error::Error,
+    skyblock_item::SkyblockItem,
+    utils::{get_timestamp_millis, strip_formatting},
+};
+use crate::{
+    response::skyblock::skyblock_auctions_response::SkyblockAuction,
+    types::{auction_category::AuctionCategory, rarity::Rarity},
+    RsPixel,
+};
+
+/// Filters auctions the same way the in-game auction house does. Every set filter must match.
+#[derive(Debug, Default, Clone)]
+pub struct AuctionQuery {
+    item_id: Option<String>,
+    name: Option<String>,
+    tier: Option<Rarity>,
+    category: Option<AuctionCategory>,
+    bin_only: bool,
+    min_price: Option<i64>,
+    max_price: Option<i64>,
+    enchantments: Vec<(String, i64)>,
+    reforge: Option<String>,
+    min_stars: Option<i64>,
+    ending_within: Option<i64>,
+    seller: Option<String>,
+}
+
+impl AuctionQuery {
+    pub fn new() -> AuctionQuery {
+        AuctionQuery::default()
+    }
+
+    /// Match the canonical id of the decoded item, see [`SkyblockItem::get_canonical_id`]
+    pub fn item_id(mut self, item_id: impl Into<String>) -> AuctionQuery {
+        self.item_id = Some(item_id.into());
+        self
+    }
+
+    /// Match a case insensitive substring of the item name. Formatting codes are ignored on both
+    /// sides.
+    pub fn name(mut self, name: impl Into<String>) -> AuctionQuery {
+        self.name = Some(strip_formatting(&name.into()).to_lowercase());
+        self
+    }
+
+    pub fn tier(mut self, tier: Rarity) -> AuctionQuery {
+        self.tier = Some(tier);
+        self
+    }
+
+    pub fn category(mut self, category: AuctionCategory) -> AuctionQuery {
+        self.category = Some(category);
+        self
+    }
+
+    pub fn bin_only(mut self, bin_only: bool) -> AuctionQuery {
+        self.bin_only = bin_only;
+        self
+    }
+
+    /// Match auctions whose current price is at least the minimum
+    pub fn min_price(mut self, min_price: i64) -> AuctionQuery {
+        self.min_price = Some(min_price);
+        self
+    }
+
+    /// Match auctions whose current price is at most the maximum
+    pub fn max_price(mut self, max_price: i64) -> AuctionQuery {
+        self.max_price = Some(max_price);
+        self
+    }
+
+    /// Require an enchantment of at least the level. Can be called multiple times.
+    pub fn enchantment(mut self, enchantment: impl Into<String>, min_level: i64) -> AuctionQuery {
+        self.enchantments
+            .push((enchantment.into().to_lowercase(), min_level));
+        self
+    }
+
+    pub fn reforge(mut self, reforge: impl Into<String>) -> AuctionQuery {
+        self.reforge = Some(reforge.into().to_lowercase());
+        self
+    }
+
+    /// Require at least this many dungeon stars
+    pub fn min_stars(mut self, min_stars: i64) -> AuctionQuery {
+        self.min_stars = Some(min_stars);
+        self
+    }
+
+    /// Match auctions that have not ended and end within the milliseconds
+    pub fn ending_within(mut self, millis: i64) -> AuctionQuery {
+        self.ending_within = Some(millis);
+        self
+    }
+
+    /// Match the auctioneer's uuid, with or without dashes
+    pub fn seller(mut self, seller: impl Into<String>) -> AuctionQuery {
+        self.seller = Some(seller.into().replace('-', "").to_lowercase());
+        self
+    }
+
+    fn needs_nbt(&self) -> bool {
+        self.item_id.is_some()
+            || !self.enchantments.is_empty()
+            || self.reforge.is_some()
+            || self.min_stars.is_some()
+    }
+
+    pub fn matches(&self, auction: &SkyblockAuction) -> bool {
+        self.matches_at(auction, get_timestamp_millis())
+    }
+
+    /// Check the auction against the query, using the timestamp as the current time
+    pub fn matches_at(&self, auction: &SkyblockAuction, now: i64) -> bool {
+        if self.bin_only && !auction.bin {
+            return false;
+        }
+
+        if let Some(name) = &self.name {
+            if !strip_formatting(&auction.item_name)
+                .to_lowercase()
+                .contains(name)
+            {
+                return false;
+            }
+        }
+
+        if self.tier.is_some_and(|tier| auction.tier != tier)
+            || self
+                .category
+                .is_some_and(|category| auction.category != category)
+        {
+            return false;
+        }
+
+        let price = auction.get_current_price();
+        if self.min_price.is_some_and(|min_price| price < min_price)
+            || self.max_price.is_some_and(|max_price| price > max_price)
+        {
+            return false;
+        }
+
+        if let Some(ending_within) = self.ending_within {
+            if auction.is_ended(now) || auction.get_time_remaining(now) > ending_within {
+                return false;
+            }
+        }
+
+        if let Some(seller) = &self.seller {
+            if !auction
+                .auctioneer
+                .replace('-', "")
+                .eq_ignore_ascii_case(seller)
+            {
+                return false;
+            }
+        }
+
+        if self.needs_nbt() {
+            match auction.get_nbt() {
+                Some(item) => self.matches_item(&item),
+                None => false,
+            }
+        } else {
+            true
+        }
+    }
+
+    fn matches_item(&self, item: &SkyblockItem) -> bool {
+        if let Some(item_id) = &self.item_id {
+            if item.get_canonical_id().as_ref() != Some(item_id) {
+                return false;
+            }
+        }
+
+        for (enchantment, min_level) in &self.enchantments {
+            if item
+                .enchantments
+                .get(enchantment)
+                .is_none_or(|level| level < min_level)
+            {
+                return false;
+            }
+        }
+
+        if let Some(reforge) = &self.reforge {
+            if !item
+                .reforge
+                .as_ref()
+                .is_some_and(|modifier| modifier.eq_ignore_ascii_case(reforge))
+            {
+                return false;
+            }
+        }
+
+        if self
+            .min_stars
+            .is_some_and(|min_stars| item.dungeon_stars < min_stars)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Get the matching auctions from a single page
+    pub fn filter<'a>(&self, auctions: &'a [SkyblockAuction]) -> Vec<&'a SkyblockAuction> {
+        let now = get_timestamp_millis();
+        auctions
+            .iter()
+            .filter(|auction| self.matches_at(auction, now))
+            .collect()
+    }
+
+    /// Walk every auction house page and collect the matching auctions
+    pub async fn search_all(&self, rs_pixel: &mut RsPixel) -> Result<Vec<SkyblockAuction>, Error> {
+        let mut matched = Vec::new();
+        let mut page = 0;
+
+        loop {
+            let response = rs_pixel.get_skyblock_auctions(page).await?;
+            matched.extend(self.filter(&response.auctions).into_iter().cloned());
+
+            page += 1;
+            if page >= response.total_pages {
+                break;
+            }
+        }
+
+        Ok(matched)
+    }
+}
@@ -1,6 +1,32 @@
+use crate::types::rarity::Rarity;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
+/// Exp added to each skill level past the leveling table
+pub const SKILL_OVERFLOW_SLOPE: i64 = 600_000;
+/// Exp needed for each catacombs level past 50
+pub const CATACOMBS_OVERFLOW_EXP: i64 = 200_000_000;
+
+/// Master stars applied for dungeon stars 6 to 10
+pub const MASTER_STARS: [&str; 5] = [
+    "FIRST_MASTER_STAR",
+    "SECOND_MASTER_STAR",
+    "THIRD_MASTER_STAR",
+    "FOURTH_MASTER_STAR",
+    "FIFTH_MASTER_STAR",
+];
+
+/// Coins to spawn each tier of the zombie, spider, wolf and enderman slayers
+pub const SLAYER_SPAWN_COSTS: [i64; 5] = [2000, 7500, 20000, 50000, 100_000];
+/// Coins to spawn each tier of the blaze slayer
+pub const BLAZE_SLAYER_SPAWN_COSTS: [i64; 4] = [10000, 25000, 60000, 150_000];
+
+/// Pet score needed for each point of magic find
+pub const PET_SCORE_MAGIC_FIND: [i64; 10] = [10, 25, 50, 75, 100, 130, 175, 225, 275, 325];
+
+/// Stats of a pet as `(stat, base, per level)`
+pub type PetStatBlock = &'static [(&'static str, f64, f64)];
+
 lazy_static! {
     pub static ref LEVELING_EXP: &'static [i64] = &[
         50, 125, 200, 300, 500, 750, 1000, 1500, 2000, 3500, 5000, 7500, 10000, 15000, 20000,
@@ -46,6 +72,7 @@ lazy_static! {
         m.insert("zombie", 9);
         m.insert("enderman", 9);
         m.insert("blaze", 9);
+        m.insert("vampire", 5);
         m
     };
     pub static ref ZOMBIE_EXP: &'static [i64] = &[5, 15, 200, 1000, 5000, 20000, 100_000, 400_000, 1_000_000];
@@ -53,6 +80,7 @@ lazy_static! {
     pub static ref WOLF_EXP: &'static [i64] = &[10, 30, 250, 1500, 5000, 20000, 100_000, 400_000, 1_000_000];
     pub static ref ENDERMAN_EXP: &'static [i64] = &[10, 30, 250, 1500, 5000, 20000, 100_000, 400_000, 1_000_000];
     pub static ref BLAZE_EXP: &'static [i64] = &[10, 30, 250, 1500, 5000, 20000, 100_000, 400_000, 1_000_000];
+    pub static ref VAMPIRE_EXP: &'static [i64] = &[20, 75, 240, 840, 2400];
     pub static ref PET_RARITY_OFFSET: HashMap<&'static str, i64> = {
         let mut m = HashMap::new();
         m.insert("COMMON", 0);
@@ -63,19 +91,166 @@ lazy_static! {
         m.insert("MYTHIC", 20);
         m
     };
-    pub static ref PET_EXP: &'static [i64] = &[
-        100, 110, 120, 130, 145, 160, 175, 190, 210, 230, 250, 275, 300, 330, 360, 400, 440, 490, 540,
-        600, 660, 730, 800, 880, 960, 1050, 1150, 1260, 1380, 1510, 1650, 1800, 1960, 2130, 2310, 2500,
-        2700, 2920, 3160, 3420, 3700, 4000, 4350, 4750, 5200, 5700, 6300, 7000, 7800, 8700, 9700,
-        10800, 12000, 13300, 14700, 16200, 17800, 19500, 21300, 23200, 25200, 27400, 29800, 32400,
-        35200, 38200, 41400, 44800, 48400, 52200, 56200, 60400, 64800, 69400, 74200, 79200, 84700,
-        90700, 97200, 104_200, 111_700, 119_700, 128_200, 137_200, 146_700, 156_700, 167_700, 179_700, 192_700,
-        206_700, 221_700, 237_700, 254_700, 272_700, 291_700, 311_700, 333_700, 357_700, 383_700, 411_700, 441_700,
-        476_700, 516_700, 561_700, 611_700, 666_700, 726_700, 791_700, 861_700, 936_700, 1_016_700, 1_101_700,
-        1_191_700, 1_286_700, 1_386_700, 1_496_700, 1_616_700, 1_746_700, 1_886_700,
-    ];
+    /// Max level of pets that don't stop at 100
+    pub static ref PET_MAX_LEVELS: HashMap<&'static str, i64> = {
+        let mut m = HashMap::new();
+        m.insert("GOLDEN_DRAGON", 200);
+        m
+    };
+    /// Stats of pets for each rarity. A pet uses the stats of the highest listed rarity at or
+    /// below its own.
+    pub static ref PET_STATS: HashMap<&'static str, &'static [(Rarity, PetStatBlock)]> = {
+        let mut m: HashMap<&'static str, &'static [(Rarity, PetStatBlock)]> = HashMap::new();
+        m.insert("TIGER", &[
+            (Rarity::Common, &[("strength", 5.0, 0.1), ("crit_chance", 0.0, 0.05), ("crit_damage", 0.0, 0.1), ("ferocity", 0.0, 0.1)]),
+            (Rarity::Uncommon, &[("strength", 5.0, 0.1), ("crit_chance", 0.0, 0.05), ("crit_damage", 0.0, 0.2), ("ferocity", 0.0, 0.1)]),
+            (Rarity::Rare, &[("strength", 5.0, 0.1), ("crit_chance", 0.0, 0.05), ("crit_damage", 0.0, 0.3), ("ferocity", 0.0, 0.25)]),
+            (Rarity::Epic, &[("strength", 5.0, 0.1), ("crit_chance", 0.0, 0.05), ("crit_damage", 0.0, 0.5), ("ferocity", 0.0, 0.25)]),
+        ]);
+        m.insert("ENDER_DRAGON", &[
+            (Rarity::Epic, &[("strength", 0.0, 0.5), ("crit_chance", 0.0, 0.1), ("crit_damage", 0.0, 0.5)]),
+        ]);
+        m.insert("GRIFFIN", &[
+            (Rarity::Common, &[("strength", 0.0, 0.25), ("crit_chance", 0.0, 0.1), ("crit_damage", 0.0, 0.5), ("intelligence", 0.0, 0.1), ("magic_find", 0.0, 0.1)]),
+        ]);
+        m.insert("SHEEP", &[
+            (Rarity::Common, &[("intelligence", 0.0, 1.0), ("ability_damage", 0.0, 0.5)]),
+        ]);
+        m.insert("ELEPHANT", &[
+            (Rarity::Common, &[("defense", 0.0, 0.15), ("intelligence", 0.0, 0.75)]),
+        ]);
+        m
+    };
+    /// Lowest and highest rarity of each pet. This only covers some of the pets, see
+    /// [`LevelingTables::with_pets_resource`](super::leveling_tables::LevelingTables::with_pets_resource)
+    /// for a complete list.
+    pub static ref PET_RARITIES: HashMap<&'static str, (Rarity, Rarity)> = {
+        let mut m = HashMap::new();
+        m.insert("BAT", (Rarity::Common, Rarity::Mythic));
+        m.insert("BLAZE", (Rarity::Epic, Rarity::Legendary));
+        m.insert("CHICKEN", (Rarity::Common, Rarity::Legendary));
+        m.insert("HORSE", (Rarity::Common, Rarity::Legendary));
+        m.insert("JERRY", (Rarity::Common, Rarity::Mythic));
+        m.insert("OCELOT", (Rarity::Common, Rarity::Legendary));
+        m.insert("PIGMAN", (Rarity::Epic, Rarity::Legendary));
+        m.insert("RABBIT", (Rarity::Common, Rarity::Mythic));
+        m.insert("SHEEP", (Rarity::Common, Rarity::Legendary));
+        m.insert("SILVERFISH", (Rarity::Common, Rarity::Legendary));
+        m.insert("WITHER_SKELETON", (Rarity::Epic, Rarity::Legendary));
+        m.insert("SKELETON_HORSE", (Rarity::Legendary, Rarity::Legendary));
+        m.insert("WOLF", (Rarity::Common, Rarity::Legendary));
+        m.insert("ENDERMAN", (Rarity::Common, Rarity::Mythic));
+        m.insert("PHOENIX", (Rarity::Epic, Rarity::Legendary));
+        m.insert("MAGMA_CUBE", (Rarity::Common, Rarity::Legendary));
+        m.insert("FLYING_FISH", (Rarity::Rare, Rarity::Mythic));
+        m.insert("BLUE_WHALE", (Rarity::Common, Rarity::Legendary));
+        m.insert("TIGER", (Rarity::Common, Rarity::Legendary));
+        m.insert("LION", (Rarity::Common, Rarity::Legendary));
+        m.insert("PARROT", (Rarity::Epic, Rarity::Legendary));
+        m.insert("SNOWMAN", (Rarity::Legendary, Rarity::Legendary));
+        m.insert("TURTLE", (Rarity::Epic, Rarity::Legendary));
+        m.insert("BEE", (Rarity::Common, Rarity::Mythic));
+        m.insert("ENDER_DRAGON", (Rarity::Epic, Rarity::Legendary));
+        m.insert("GUARDIAN", (Rarity::Common, Rarity::Mythic));
+        m.insert("SQUID", (Rarity::Common, Rarity::Legendary));
+        m.insert("GIRAFFE", (Rarity::Common, Rarity::Legendary));
+        m.insert("ELEPHANT", (Rarity::Common, Rarity::Legendary));
+        m.insert("MONKEY", (Rarity::Common, Rarity::Legendary));
+        m.insert("SPIDER", (Rarity::Common, Rarity::Mythic));
+        m.insert("ENDERMITE", (Rarity::Common, Rarity::Mythic));
+        m.insert("GHOUL", (Rarity::Epic, Rarity::Legendary));
+        m.insert("JELLYFISH", (Rarity::Epic, Rarity::Legendary));
+        m.insert("PIG", (Rarity::Common, Rarity::Legendary));
+        m.insert("ROCK", (Rarity::Common, Rarity::Legendary));
+        m.insert("SKELETON", (Rarity::Common, Rarity::Mythic));
+        m.insert("ZOMBIE", (Rarity::Common, Rarity::Legendary));
+        m.insert("DOLPHIN", (Rarity::Common, Rarity::Legendary));
+        m.insert("BABY_YETI", (Rarity::Epic, Rarity::Legendary));
+        m.insert("MEGALODON", (Rarity::Epic, Rarity::Legendary));
+        m.insert("GOLEM", (Rarity::Epic, Rarity::Legendary));
+        m.insert("HOUND", (Rarity::Epic, Rarity::Legendary));
+        m.insert("TARANTULA", (Rarity::Common, Rarity::Mythic));
+        m.insert("BLACK_CAT", (Rarity::Legendary, Rarity::Mythic));
+        m.insert("SPIRIT", (Rarity::Epic, Rarity::Legendary));
+        m.insert("GRIFFIN", (Rarity::Common, Rarity::Legendary));
+        m.insert("MITHRIL_GOLEM", (Rarity::Common, Rarity::Mythic));
+        m.insert("GRANDMA_WOLF", (Rarity::Common, Rarity::Legendary));
+        m.insert("ARMADILLO", (Rarity::Common, Rarity::Legendary));
+        m.insert("BAL", (Rarity::Epic, Rarity::Legendary));
+        m.insert("SCATHA", (Rarity::Rare, Rarity::Legendary));
+        m.insert("GOLDEN_DRAGON", (Rarity::Legendary, Rarity::Legendary));
+        m.insert("AMMONITE", (Rarity::Legendary, Rarity::Legendary));
+        m.insert("SNAIL", (Rarity::Common, Rarity::Legendary));
+        m.insert("KUUDRA", (Rarity::Common, Rarity::Legendary));
+        m
+    };
+    /// Exp for each pet level, offset by rarity. Golden dragons level past 100 with the entries
+    /// after the first 119.
+    pub static ref PET_EXP: Vec<i64> = {
+        let mut exp = vec![
+            100, 110, 120, 130, 145, 160, 175, 190, 210, 230, 250, 275, 300, 330, 360, 400, 440, 490, 540,
+            600, 660, 730, 800, 880, 960, 1050, 1150, 1260, 1380, 1510, 1650, 1800, 1960, 2130, 2310, 2500,
+            2700, 2920, 3160, 3420, 3700, 4000, 4350, 4750, 5200, 5700, 6300, 7000, 7800, 8700, 9700,
+            10800, 12000, 13300, 14700, 16200, 17800, 19500, 21300, 23200, 25200, 27400, 29800, 32400,
+            35200, 38200, 41400, 44800, 48400, 52200, 56200, 60400, 64800, 69400, 74200, 79200, 84700,
+            90700, 97200, 104_200, 111_700, 119_700, 128_200, 137_200, 146_700, 156_700, 167_700, 179_700, 192_700,
+            206_700, 221_700, 237_700, 254_700, 272_700, 291_700, 311_700, 333_700, 357_700, 383_700, 411_700, 441_700,
+            476_700, 516_700, 561_700, 611_700, 666_700, 726_700, 791_700, 861_700, 936_700, 1_016_700, 1_101_700,
+            1_191_700, 1_286_700, 1_386_700, 1_496_700, 1_616_700, 1_746_700, 1_886_700,
+        ];
+        exp.extend([0, 5_555]);
+        exp.extend([1_886_700; 98]);
+        exp
+    };
 
     // Other
+    /// Reforge stone for each reforge applied with one
+    pub static ref REFORGE_STONES: HashMap<&'static str, &'static str> = {
+        let mut m = HashMap::new();
+        m.insert("fabled", "DRAGON_CLAW");
+        m.insert("withered", "WITHER_BLOOD");
+        m.insert("ancient", "PRECURSOR_GEAR");
+        m.insert("giant", "GIANT_TOOTH");
+        m.insert("spiritual", "SPIRIT_DECOY");
+        m.insert("renowned", "DRAGON_HORN");
+        m.insert("loving", "RED_SCARF");
+        m.insert("submerged", "DEEP_SEA_ORB");
+        m.insert("jaded", "JADERALD");
+        m.insert("fleet", "DIAMONITE");
+        m.insert("auspicious", "ROCK_GEMSTONE");
+        m.insert("mossy", "OVERGROWN_GRASS");
+        m.insert("empowered", "SADAN_BROOCH");
+        m.insert("perfect", "DIAMOND_ATOM");
+        m.insert("necrotic", "NECROMANCER_BROOCH");
+        m.insert("undead", "PREMIUM_FLESH");
+        m.insert("bountiful", "GOLDEN_BALL");
+        m.insert("suspicious", "SUSPICIOUS_VIAL");
+        m.insert("gilded", "MIDAS_JEWEL");
+        m.insert("warped", "WARPED_STONE");
+        m.insert("toil", "TOIL_LOG");
+        m.insert("blessed", "BLESSED_FRUIT");
+        m.insert("treacherous", "RUSTY_ANCHOR");
+        m.insert("dirty", "DIRT_BOTTLE");
+        m.insert("moil", "MOIL_LOG");
+        m.insert("refined", "REFINED_AMBER");
+        m.insert("strengthened", "SEARING_STONE");
+        m.insert("glistening", "SHINY_PRISM");
+        m.insert("bustling", "SKYMART_BROCHURE");
+        m.insert("rooted", "BURROWING_SPORES");
+        m.insert("lucky", "LUCKY_DICE");
+        m.insert("stiff", "HARDENED_WOOD");
+        m.insert("precise", "OPTICAL_LENS");
+        m.insert("spiked", "DRAGON_SCALE");
+        m.insert("cubic", "MOLTEN_CUBE");
+        m.insert("reinforced", "RARE_DIAMOND");
+        m.insert("salty", "SALT_CUBE");
+        m.insert("magnetic", "LAPIS_CRYSTAL");
+        m.insert("fortified", "METEOR_SHARD");
+        m.insert("ridiculous", "RED_NOSE");
+        m.insert("festive", "FROZEN_BAUBLE");
+        m.insert("hyper", "END_STONE_GEODE");
+        m
+    };
     pub static ref CRAFTED_MINIONS_TO_SLOTS: &'static [i64] = &[
         0, 5, 15, 30, 50, 75, 100, 125, 150, 175, 200, 225, 250, 275, 300, 350, 400, 450, 500, 550, 600,
     ];
@@ -5,6 +5,10 @@ use std::fmt;
 pub enum Error {
     Client(surf::Error),
     Parse(serde_json::Error),
+    Io(std::io::Error),
+    Base64(base64::DecodeError),
+    Gzip(std::io::Error),
+    Nbt(String),
     Status(u16, String),
     RateLimit(i64),
     Unknown(String),
@@ -25,6 +29,18 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<base64::DecodeError> for Error {
+    fn from(e: base64::DecodeError) -> Self {
+        Error::Base64(e)
+    }
+}
+
 impl From<String> for Error {
     fn from(e: String) -> Self {
         Error::Unknown(e)
@@ -42,6 +58,10 @@ impl fmt::Display for Error {
         match *self {
             Error::Client(ref err) => err.fmt(f),
             Error::Parse(ref err) => err.fmt(f),
+            Error::Io(ref err) => err.fmt(f),
+            Error::Base64(ref err) => write!(f, "Invalid base64: {err}"),
+            Error::Gzip(ref err) => write!(f, "Invalid gzip: {err}"),
+            Error::Nbt(ref err) => write!(f, "Malformed NBT: {err}"),
             Error::Unknown(ref err) => err.fmt(f),
             Error::Status(ref code, ref err) => write!(f, "{code} {err}"),
             Error::RateLimit(ref time_till_reset) => write!(
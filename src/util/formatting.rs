@@ -0,0 +1,299 @@
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChatColor {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+}
+
+impl ChatColor {
+    pub fn from_code(code: char) -> Option<ChatColor> {
+        Some(match code.to_ascii_lowercase() {
+            '0' => ChatColor::Black,
+            '1' => ChatColor::DarkBlue,
+            '2' => ChatColor::DarkGreen,
+            '3' => ChatColor::DarkAqua,
+            '4' => ChatColor::DarkRed,
+            '5' => ChatColor::DarkPurple,
+            '6' => ChatColor::Gold,
+            '7' => ChatColor::Gray,
+            '8' => ChatColor::DarkGray,
+            '9' => ChatColor::Blue,
+            'a' => ChatColor::Green,
+            'b' => ChatColor::Aqua,
+            'c' => ChatColor::Red,
+            'd' => ChatColor::LightPurple,
+            'e' => ChatColor::Yellow,
+            'f' => ChatColor::White,
+            _ => return None,
+        })
+    }
+
+    /// Get the color from its name (e.g. `DARK_AQUA`), as used by guild tag and rank colors
+    pub fn from_name(name: &str) -> Option<ChatColor> {
+        Some(match name.to_uppercase().as_str() {
+            "BLACK" => ChatColor::Black,
+            "DARK_BLUE" => ChatColor::DarkBlue,
+            "DARK_GREEN" => ChatColor::DarkGreen,
+            "DARK_AQUA" => ChatColor::DarkAqua,
+            "DARK_RED" => ChatColor::DarkRed,
+            "DARK_PURPLE" => ChatColor::DarkPurple,
+            "GOLD" => ChatColor::Gold,
+            "GRAY" => ChatColor::Gray,
+            "DARK_GRAY" => ChatColor::DarkGray,
+            "BLUE" => ChatColor::Blue,
+            "GREEN" => ChatColor::Green,
+            "AQUA" => ChatColor::Aqua,
+            "RED" => ChatColor::Red,
+            "LIGHT_PURPLE" => ChatColor::LightPurple,
+            "YELLOW" => ChatColor::Yellow,
+            "WHITE" => ChatColor::White,
+            _ => return None,
+        })
+    }
+
+    pub fn get_code(&self) -> char {
+        match self {
+            ChatColor::Black => '0',
+            ChatColor::DarkBlue => '1',
+            ChatColor::DarkGreen => '2',
+            ChatColor::DarkAqua => '3',
+            ChatColor::DarkRed => '4',
+            ChatColor::DarkPurple => '5',
+            ChatColor::Gold => '6',
+            ChatColor::Gray => '7',
+            ChatColor::DarkGray => '8',
+            ChatColor::Blue => '9',
+            ChatColor::Green => 'a',
+            ChatColor::Aqua => 'b',
+            ChatColor::Red => 'c',
+            ChatColor::LightPurple => 'd',
+            ChatColor::Yellow => 'e',
+            ChatColor::White => 'f',
+        }
+    }
+
+    /// Get the formatting code, such as `§6`
+    pub fn get_formatting_code(&self) -> String {
+        format!("§{}", self.get_code())
+    }
+
+    pub fn get_hex(&self) -> &'static str {
+        match self {
+            ChatColor::Black => "#000000",
+            ChatColor::DarkBlue => "#0000AA",
+            ChatColor::DarkGreen => "#00AA00",
+            ChatColor::DarkAqua => "#00AAAA",
+            ChatColor::DarkRed => "#AA0000",
+            ChatColor::DarkPurple => "#AA00AA",
+            ChatColor::Gold => "#FFAA00",
+            ChatColor::Gray => "#AAAAAA",
+            ChatColor::DarkGray => "#555555",
+            ChatColor::Blue => "#5555FF",
+            ChatColor::Green => "#55FF55",
+            ChatColor::Aqua => "#55FFFF",
+            ChatColor::Red => "#FF5555",
+            ChatColor::LightPurple => "#FF55FF",
+            ChatColor::Yellow => "#FFFF55",
+            ChatColor::White => "#FFFFFF",
+        }
+    }
+
+    pub fn get_ansi_code(&self) -> u8 {
+        match self {
+            ChatColor::Black => 30,
+            ChatColor::DarkBlue => 34,
+            ChatColor::DarkGreen => 32,
+            ChatColor::DarkAqua => 36,
+            ChatColor::DarkRed => 31,
+            ChatColor::DarkPurple => 35,
+            ChatColor::Gold => 33,
+            ChatColor::Gray => 37,
+            ChatColor::DarkGray => 90,
+            ChatColor::Blue => 94,
+            ChatColor::Green => 92,
+            ChatColor::Aqua => 96,
+            ChatColor::Red => 91,
+            ChatColor::LightPurple => 95,
+            ChatColor::Yellow => 93,
+            ChatColor::White => 97,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    pub color: Option<ChatColor>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underlined: bool,
+    pub strikethrough: bool,
+    pub obfuscated: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyledSpan {
+    pub text: String,
+    pub style: Style,
+}
+
+/// Split text with `§` formatting codes into styled spans. Like Minecraft, a color code resets
+/// the formatting, `§r` resets everything, and unknown codes are ignored.
+pub fn parse(text: &str) -> Vec<StyledSpan> {
+    let mut spans = Vec::new();
+    let mut style = Style::default();
+    let mut current = String::new();
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '§' {
+            current.push(c);
+            continue;
+        }
+
+        let Some(code) = chars.next() else {
+            break;
+        };
+
+        let mut new_style = style;
+        if let Some(color) = ChatColor::from_code(code) {
+            new_style = Style {
+                color: Some(color),
+                ..Style::default()
+            };
+        } else {
+            match code.to_ascii_lowercase() {
+                'k' => new_style.obfuscated = true,
+                'l' => new_style.bold = true,
+                'm' => new_style.strikethrough = true,
+                'n' => new_style.underlined = true,
+                'o' => new_style.italic = true,
+                'r' => new_style = Style::default(),
+                _ => {}
+            }
+        }
+
+        if new_style != style {
+            if !current.is_empty() {
+                spans.push(StyledSpan {
+                    text: std::mem::take(&mut current),
+                    style,
+                });
+            }
+            style = new_style;
+        }
+    }
+
+    if !current.is_empty() {
+        spans.push(StyledSpan {
+            text: current,
+            style,
+        });
+    }
+
+    spans
+}
+
+/// Remove all formatting codes
+pub fn to_plain(text: &str) -> String {
+    parse(text).into_iter().map(|span| span.text).collect()
+}
+
+/// Render with ANSI escape codes for terminals
+pub fn to_ansi(text: &str) -> String {
+    let mut ansi = String::new();
+
+    for span in parse(text) {
+        let mut codes = Vec::new();
+        if let Some(color) = span.style.color {
+            codes.push(color.get_ansi_code());
+        }
+        if span.style.bold {
+            codes.push(1);
+        }
+        if span.style.italic {
+            codes.push(3);
+        }
+        if span.style.underlined {
+            codes.push(4);
+        }
+        if span.style.strikethrough {
+            codes.push(9);
+        }
+
+        if codes.is_empty() {
+            ansi.push_str(&span.text);
+        } else {
+            let codes = codes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(";");
+            let _ = write!(ansi, "\x1b[{codes}m{}\x1b[0m", span.text);
+        }
+    }
+
+    ansi
+}
+
+/// Render as HTML `span` elements with inline styles. The text is escaped.
+pub fn to_html(text: &str) -> String {
+    let mut html = String::new();
+
+    for span in parse(text) {
+        let mut css = Vec::new();
+        if let Some(color) = span.style.color {
+            css.push(format!("color:{}", color.get_hex()));
+        }
+        if span.style.bold {
+            css.push("font-weight:bold".to_string());
+        }
+        if span.style.italic {
+            css.push("font-style:italic".to_string());
+        }
+        match (span.style.underlined, span.style.strikethrough) {
+            (true, true) => css.push("text-decoration:underline line-through".to_string()),
+            (true, false) => css.push("text-decoration:underline".to_string()),
+            (false, true) => css.push("text-decoration:line-through".to_string()),
+            (false, false) => {}
+        }
+
+        let escaped = escape_html(&span.text);
+        if css.is_empty() {
+            html.push_str(&escaped);
+        } else {
+            let _ = write!(html, "<span style=\"{}\">{escaped}</span>", css.join(";"));
+        }
+    }
+
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
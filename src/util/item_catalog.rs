@@ -0,0 +1,204 @@
+use super::{error::Error, formatting::to_plain, item_value::UpgradeCostSource};
+use crate::{
+    response::resources::skyblock_items_response::{SkyblockItemResource, SkyblockItemsResponse},
+    HypixelEndpoint, RsPixel,
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// Metadata of every SkyBlock item from the items resource
+#[derive(Debug, Clone, Default)]
+pub struct ItemCatalog {
+    items: HashMap<String, SkyblockItemResource>,
+    /// Normalized display name of each item id
+    names: Vec<(String, String)>,
+}
+
+impl ItemCatalog {
+    pub fn from_response(response: &SkyblockItemsResponse) -> ItemCatalog {
+        let mut names = response
+            .items
+            .iter()
+            .map(|item| (normalize_name(&item.name), item.id.clone()))
+            .collect::<Vec<_>>();
+        names.sort();
+
+        ItemCatalog {
+            items: response
+                .items
+                .iter()
+                .map(|item| (item.id.clone(), item.clone()))
+                .collect(),
+            names,
+        }
+    }
+
+    /// Build the catalog from the items resource. If the resource has a cache TTL, the built
+    /// catalog is cached with the same TTL.
+    pub async fn fetch(rs_pixel: &mut RsPixel) -> Result<Arc<ItemCatalog>, Error> {
+        let endpoint = HypixelEndpoint::RESOURCES_SKYBLOCK_ITEMS;
+        let cache_key = format!("{}-catalog", endpoint.get_path());
+
+        if let Some(cache) = &rs_pixel.config.cache {
+            if let Some(cached) = cache.get(&cache_key) {
+                if let Ok(catalog) = cached.1.downcast::<ItemCatalog>() {
+                    return Ok(catalog);
+                }
+            }
+        }
+
+        let response = rs_pixel.get_resources_skyblock_items().await?;
+        let catalog = Arc::new(ItemCatalog::from_response(&response));
+
+        if let Some(cache) = &rs_pixel.config.cache {
+            if let Some(ttl) = rs_pixel.config.hypixel_cache_ttls.get(&endpoint) {
+                cache.insert(cache_key, (*ttl, catalog.clone())).await;
+            }
+        }
+
+        Ok(catalog)
+    }
+
+    /// Get an item by SkyBlock id
+    pub fn get(&self, id: &str) -> Option<&SkyblockItemResource> {
+        self.items.get(id)
+    }
+
+    /// Get the closest item by display name, ignoring case and formatting codes
+    pub fn get_by_name(&self, name: &str) -> Option<&SkyblockItemResource> {
+        self.search_by_name(name).into_iter().next()
+    }
+
+    /// Get the items matching a display name, best match first. Exact matches come first, then
+    /// names starting with or containing the query, then names with every word of the query, then
+    /// names within a few typos.
+    pub fn search_by_name(&self, query: &str) -> Vec<&SkyblockItemResource> {
+        let query = normalize_name(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let words = query.split(' ').collect::<Vec<_>>();
+        let max_distance = (query.len() / 4).max(1);
+
+        let mut matches = self
+            .names
+            .iter()
+            .filter_map(|(name, id)| {
+                let score = if *name == query {
+                    0
+                } else if name.starts_with(&query) {
+                    1
+                } else if name.contains(&query) {
+                    2
+                } else if words.iter().all(|word| name.contains(word)) {
+                    3
+                } else {
+                    let distance = levenshtein(name, &query);
+                    if distance > max_distance {
+                        return None;
+                    }
+                    4 + distance
+                };
+                Some((score, name.len(), id))
+            })
+            .collect::<Vec<_>>();
+        matches.sort();
+
+        matches
+            .into_iter()
+            .filter_map(|(_, _, id)| self.items.get(id))
+            .collect()
+    }
+
+    pub fn get_npc_sell_price(&self, id: &str) -> Option<f64> {
+        self.get(id).and_then(|item| item.npc_sell_price)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SkyblockItemResource> {
+        self.items.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl UpgradeCostSource for ItemCatalog {
+    fn get_star_costs(&self, item_id: &str, star: i64) -> Vec<(String, i64)> {
+        self.get(item_id)
+            .and_then(|item| item.upgrade_costs.get((star - 1) as usize))
+            .map(|costs| {
+                costs
+                    .iter()
+                    .filter_map(|cost| match cost.cost_type.as_str() {
+                        "ESSENCE" => cost
+                            .essence_type
+                            .as_ref()
+                            .map(|essence| (format!("ESSENCE_{essence}"), cost.amount)),
+                        _ => cost.item_id.clone().map(|id| (id, cost.amount)),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_gemstone_slot_costs(&self, item_id: &str, slot: &str) -> Vec<(String, i64)> {
+        let Some((slot_type, index)) = slot
+            .rsplit_once('_')
+            .and_then(|(slot_type, index)| Some((slot_type, index.parse::<usize>().ok()?)))
+        else {
+            return Vec::new();
+        };
+
+        self.get(item_id)
+            .and_then(|item| {
+                item.gemstone_slots
+                    .iter()
+                    .filter(|gemstone_slot| gemstone_slot.slot_type == slot_type)
+                    .nth(index)
+            })
+            .map(|gemstone_slot| {
+                gemstone_slot
+                    .costs
+                    .iter()
+                    .filter_map(|cost| match cost.cost_type.as_str() {
+                        "COINS" => cost.coins.map(|coins| ("COINS".to_string(), coins)),
+                        _ => cost
+                            .item_id
+                            .clone()
+                            .map(|id| (id, cost.amount.unwrap_or(1))),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Lowercase with formatting codes and symbols removed and single spaces between words
+fn normalize_name(name: &str) -> String {
+    to_plain(name)
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
@@ -0,0 +1,344 @@
+use super::{
+    constants::{MASTER_STARS, REFORGE_STONES},
+    formatting::to_plain,
+    networth::PriceSource,
+    skyblock_item::SkyblockItem,
+    skyblock_profile::PetStruct,
+};
+
+/// Costs of item upgrades that depend on the item, such as the essence for dungeon stars
+pub trait UpgradeCostSource {
+    /// Get the items needed for a dungeon star (1 to 5) as `(item id, amount)`. Coins use `COINS`.
+    fn get_star_costs(&self, item_id: &str, star: i64) -> Vec<(String, i64)>;
+
+    /// Get the items needed to unlock a gemstone slot (e.g. `JASPER_0`) as `(item id, amount)`
+    fn get_gemstone_slot_costs(&self, item_id: &str, slot: &str) -> Vec<(String, i64)>;
+}
+
+/// Share of the price of each upgrade that is added to the item value
+#[derive(Debug, Clone)]
+pub struct ItemValuationConfig {
+    pub enchantment: f64,
+    pub recombobulator: f64,
+    pub hot_potato_book: f64,
+    pub fuming_potato_book: f64,
+    pub art_of_war: f64,
+    pub dungeon_star: f64,
+    pub master_star: f64,
+    pub gemstone: f64,
+    pub gemstone_slot: f64,
+    pub reforge: f64,
+    pub pet_held_item: f64,
+    /// Share of the pet value kept when candy was used on a pet below level 100
+    pub pet_candy: f64,
+}
+
+impl Default for ItemValuationConfig {
+    fn default() -> Self {
+        ItemValuationConfig {
+            enchantment: 0.85,
+            recombobulator: 0.8,
+            hot_potato_book: 1.0,
+            fuming_potato_book: 0.6,
+            art_of_war: 0.6,
+            dungeon_star: 1.0,
+            master_star: 1.0,
+            gemstone: 1.0,
+            gemstone_slot: 0.6,
+            reforge: 1.0,
+            pet_held_item: 1.0,
+            pet_candy: 0.65,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueComponentKind {
+    Enchantment,
+    Recombobulator,
+    HotPotatoBook,
+    FumingPotatoBook,
+    ArtOfWar,
+    DungeonStar,
+    MasterStar,
+    Gemstone,
+    GemstoneSlot,
+    Reforge,
+    PetHeldItem,
+    /// Value lost from pet candy, which is negative
+    PetCandy,
+}
+
+#[derive(Debug, Clone)]
+pub struct ValueComponent {
+    pub kind: ValueComponentKind,
+    /// Id of the item the value comes from (e.g. `ENCHANTMENT_SHARPNESS_6`)
+    pub item_id: String,
+    pub count: i64,
+    /// Value added to the item, after the multiplier
+    pub value: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemValue {
+    pub item_id: String,
+    /// Display name without formatting codes
+    pub name: String,
+    pub count: i64,
+    /// Price of one item without upgrades, or `None` if the price source has no price
+    pub base_price: Option<f64>,
+    /// Upgrades applied to the item, in the order they were valued
+    pub components: Vec<ValueComponent>,
+    /// Value of the whole stack including upgrades
+    pub total: f64,
+}
+
+/// Values items from their price and upgrades
+pub struct ItemValuator<'a> {
+    prices: &'a dyn PriceSource,
+    config: ItemValuationConfig,
+    upgrade_costs: Option<&'a dyn UpgradeCostSource>,
+}
+
+impl<'a> ItemValuator<'a> {
+    pub fn new(prices: &'a dyn PriceSource) -> ItemValuator<'a> {
+        ItemValuator {
+            prices,
+            config: ItemValuationConfig::default(),
+            upgrade_costs: None,
+        }
+    }
+
+    pub fn config(mut self, config: ItemValuationConfig) -> ItemValuator<'a> {
+        self.config = config;
+        self
+    }
+
+    /// Set the source of dungeon star and gemstone slot costs, such as an
+    /// [`ItemCatalog`](super::item_catalog::ItemCatalog). Without one, these aren't valued.
+    pub fn upgrade_costs(mut self, upgrade_costs: &'a dyn UpgradeCostSource) -> ItemValuator<'a> {
+        self.upgrade_costs = Some(upgrade_costs);
+        self
+    }
+
+    fn get_price(&self, item_id: &str) -> Option<f64> {
+        if item_id == "COINS" {
+            Some(1.0)
+        } else {
+            self.prices.get_price(item_id)
+        }
+    }
+
+    fn add_component(
+        &self,
+        components: &mut Vec<ValueComponent>,
+        kind: ValueComponentKind,
+        item_id: &str,
+        count: i64,
+        multiplier: f64,
+    ) {
+        if count <= 0 {
+            return;
+        }
+
+        if let Some(price) = self.get_price(item_id) {
+            components.push(ValueComponent {
+                kind,
+                item_id: item_id.to_string(),
+                count,
+                value: price * count as f64 * multiplier,
+            });
+        }
+    }
+
+    /// Value an item. Returns `None` for items without a SkyBlock id.
+    pub fn value_item(&self, item: &SkyblockItem) -> Option<ItemValue> {
+        if let Some(pet) = item.pet_info.as_ref().and_then(PetStruct::from_raw) {
+            return Some(self.value_pet(&pet));
+        }
+
+        let item_id = item.get_canonical_id()?;
+        let skyblock_id = item.skyblock_id.as_deref().unwrap_or(&item_id);
+        let config = &self.config;
+        let mut components = Vec::new();
+
+        if skyblock_id != "ENCHANTED_BOOK" {
+            let mut enchantments = item.enchantments.iter().collect::<Vec<_>>();
+            enchantments.sort();
+            for (name, level) in enchantments {
+                self.add_component(
+                    &mut components,
+                    ValueComponentKind::Enchantment,
+                    &format!("ENCHANTMENT_{}_{}", name.to_uppercase(), level),
+                    1,
+                    config.enchantment,
+                );
+            }
+        }
+
+        if item.recombobulated {
+            self.add_component(
+                &mut components,
+                ValueComponentKind::Recombobulator,
+                "RECOMBOBULATOR_3000",
+                1,
+                config.recombobulator,
+            );
+        }
+
+        self.add_component(
+            &mut components,
+            ValueComponentKind::HotPotatoBook,
+            "HOT_POTATO_BOOK",
+            item.hot_potato_count.min(10),
+            config.hot_potato_book,
+        );
+        self.add_component(
+            &mut components,
+            ValueComponentKind::FumingPotatoBook,
+            "FUMING_POTATO_BOOK",
+            item.hot_potato_count - 10,
+            config.fuming_potato_book,
+        );
+
+        if let Some(art_of_war_count) = item
+            .nbt
+            .get_int_property("tag.ExtraAttributes.art_of_war_count")
+        {
+            self.add_component(
+                &mut components,
+                ValueComponentKind::ArtOfWar,
+                "THE_ART_OF_WAR",
+                art_of_war_count,
+                config.art_of_war,
+            );
+        }
+
+        if let Some(upgrade_costs) = self.upgrade_costs {
+            for star in 1..=item.dungeon_stars.min(5) {
+                for (cost_id, amount) in upgrade_costs.get_star_costs(skyblock_id, star) {
+                    self.add_component(
+                        &mut components,
+                        ValueComponentKind::DungeonStar,
+                        &cost_id,
+                        amount,
+                        config.dungeon_star,
+                    );
+                }
+            }
+
+            for slot in &item.unlocked_gemstone_slots {
+                for (cost_id, amount) in upgrade_costs.get_gemstone_slot_costs(skyblock_id, slot) {
+                    self.add_component(
+                        &mut components,
+                        ValueComponentKind::GemstoneSlot,
+                        &cost_id,
+                        amount,
+                        config.gemstone_slot,
+                    );
+                }
+            }
+        }
+
+        for master_star in MASTER_STARS
+            .iter()
+            .take((item.dungeon_stars - 5).max(0) as usize)
+        {
+            self.add_component(
+                &mut components,
+                ValueComponentKind::MasterStar,
+                master_star,
+                1,
+                config.master_star,
+            );
+        }
+
+        for gemstone in &item.gemstones {
+            self.add_component(
+                &mut components,
+                ValueComponentKind::Gemstone,
+                &format!("{}_{}_GEM", gemstone.quality, gemstone.gem_type),
+                1,
+                config.gemstone,
+            );
+        }
+
+        if let Some(stone) = item
+            .reforge
+            .as_deref()
+            .and_then(|reforge| REFORGE_STONES.get(reforge.to_lowercase().as_str()))
+        {
+            self.add_component(
+                &mut components,
+                ValueComponentKind::Reforge,
+                stone,
+                1,
+                config.reforge,
+            );
+        }
+
+        let name = item
+            .name
+            .as_deref()
+            .map_or_else(|| item_id.clone(), to_plain);
+        Some(self.to_item_value(item_id, name, item.count, components))
+    }
+
+    /// Value a pet. Candy only lowers the value of pets that aren't max level.
+    pub fn value_pet(&self, pet: &PetStruct) -> ItemValue {
+        let item_id = format!("PET_{}_{}", pet.name, pet.rarity);
+        let mut components = Vec::new();
+
+        if let Some(held_item) = &pet.held_item {
+            self.add_component(
+                &mut components,
+                ValueComponentKind::PetHeldItem,
+                held_item,
+                1,
+                self.config.pet_held_item,
+            );
+        }
+
+        if pet.candy_used > 0 && !pet.is_maxed() {
+            if let Some(price) = self.get_price(&item_id) {
+                components.push(ValueComponent {
+                    kind: ValueComponentKind::PetCandy,
+                    item_id: "PET_CANDY".to_string(),
+                    count: pet.candy_used,
+                    value: -price * (1.0 - self.config.pet_candy),
+                });
+            }
+        }
+
+        self.to_item_value(item_id, pet.display_name.clone(), 1, components)
+    }
+
+    /// Value a stack of an item without upgrades, such as items in sacks
+    pub fn value_stack(&self, item_id: &str, count: i64) -> ItemValue {
+        self.to_item_value(item_id.to_string(), item_id.to_string(), count, Vec::new())
+    }
+
+    fn to_item_value(
+        &self,
+        item_id: String,
+        name: String,
+        count: i64,
+        components: Vec<ValueComponent>,
+    ) -> ItemValue {
+        let base_price = self.get_price(&item_id);
+        let total = base_price.unwrap_or(0.0) * count as f64
+            + components
+                .iter()
+                .map(|component| component.value)
+                .sum::<f64>();
+
+        ItemValue {
+            item_id,
+            name,
+            count,
+            base_price,
+            components,
+            total,
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use super::constants::{
+    BLAZE_EXP, CATACOMBS_EXP, ENDERMAN_EXP, HOTM_EXP, LEVELING_CAPS, LEVELING_EXP, PET_EXP,
+    PET_MAX_LEVELS, PET_RARITIES, PET_RARITY_OFFSET, RUNECRAFTING_EXP, SOCIAL_EXP, SPIDER_EXP,
+    VAMPIRE_EXP, WOLF_EXP, ZOMBIE_EXP,
+};
+use crate::{
+    response::resources::skyblock_skills_response::SkyblockSkillsResponse,
+    types::{rarity::Rarity, skill::Skill, slayer::Slayer},
+};
+use lazy_static::lazy_static;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// Levels of the farming cap unlocked from Jacob, which the skills resource includes in the max
+/// farming level
+const MAX_FARMING_CAP_UPGRADE: i64 = 10;
+
+lazy_static! {
+    static ref CURRENT_TABLES: RwLock<Arc<LevelingTables>> =
+        RwLock::new(Arc::new(LevelingTables::default()));
+}
+
+/// Experience tables and level caps used to calculate levels. The current tables start as the
+/// ones embedded in [`constants`](super::constants) and can be replaced at runtime, such as with
+/// tables built from the skills resource, without a new release.
+#[derive(Debug, Clone)]
+pub struct LevelingTables {
+    /// Experience needed for each level
+    pub skills: HashMap<Skill, Vec<i64>>,
+    /// Max level of each skill, not including the farming level cap upgrade
+    pub skill_max_levels: HashMap<Skill, i64>,
+    /// Experience needed for each level
+    pub catacombs: Vec<i64>,
+    /// Experience needed for each level
+    pub hotm: Vec<i64>,
+    /// Total experience needed for each level
+    pub slayers: HashMap<Slayer, Vec<i64>>,
+    /// Experience needed for each level, starting at the offset of the pet's rarity
+    pub pets: Vec<i64>,
+    pub pet_rarity_offsets: HashMap<Rarity, i64>,
+    /// Max level of pets that don't stop at 100
+    pub pet_max_levels: HashMap<String, i64>,
+    /// Lowest and highest rarity of each pet type. The embedded table is incomplete, so pets it
+    /// lacks are never reported as missing unless it is replaced with
+    /// [`with_pets_resource`](LevelingTables::with_pets_resource).
+    pub pet_rarities: HashMap<String, (Rarity, Rarity)>,
+}
+
+impl Default for LevelingTables {
+    fn default() -> Self {
+        LevelingTables {
+            skills: Skill::ALL
+                .into_iter()
+                .map(|skill| {
+                    let table = match skill {
+                        Skill::Runecrafting => *RUNECRAFTING_EXP,
+                        Skill::Social => *SOCIAL_EXP,
+                        _ => *LEVELING_EXP,
+                    };
+                    (skill, table.to_vec())
+                })
+                .collect(),
+            skill_max_levels: Skill::ALL
+                .into_iter()
+                .map(|skill| (skill, LEVELING_CAPS[skill.name()]))
+                .collect(),
+            catacombs: CATACOMBS_EXP.to_vec(),
+            hotm: HOTM_EXP.to_vec(),
+            slayers: Slayer::ALL
+                .into_iter()
+                .map(|slayer| {
+                    let table = match slayer {
+                        Slayer::Zombie => *ZOMBIE_EXP,
+                        Slayer::Spider => *SPIDER_EXP,
+                        Slayer::Wolf => *WOLF_EXP,
+                        Slayer::Enderman => *ENDERMAN_EXP,
+                        Slayer::Blaze => *BLAZE_EXP,
+                        Slayer::Vampire => *VAMPIRE_EXP,
+                    };
+                    (slayer, table.to_vec())
+                })
+                .collect(),
+            pets: PET_EXP.to_vec(),
+            pet_rarity_offsets: PET_RARITY_OFFSET
+                .iter()
+                .map(|(rarity, offset)| (Rarity::from(*rarity), *offset))
+                .collect(),
+            pet_max_levels: PET_MAX_LEVELS
+                .iter()
+                .map(|(pet_type, max_level)| (pet_type.to_string(), *max_level))
+                .collect(),
+            pet_rarities: PET_RARITIES
+                .iter()
+                .map(|(pet_type, rarities)| (pet_type.to_string(), *rarities))
+                .collect(),
+        }
+    }
+}
+
+impl LevelingTables {
+    /// Get the tables used by the leveling calculations
+    pub fn current() -> Arc<LevelingTables> {
+        CURRENT_TABLES
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// Replace the tables used by the leveling calculations
+    pub fn set_current(tables: LevelingTables) {
+        *CURRENT_TABLES
+            .write()
+            .unwrap_or_else(|err| err.into_inner()) = Arc::new(tables);
+    }
+
+    /// Build tables with the skills from the skills resource. Skills missing from the resource and
+    /// the tables it doesn't have (catacombs, slayers, pets) keep their embedded defaults.
+    pub fn from_skills_resource(resource: &SkyblockSkillsResponse) -> LevelingTables {
+        let mut tables = LevelingTables::default();
+
+        for (id, skill_resource) in &resource.skills {
+            let Some(skill) = Skill::from_name(id) else {
+                continue;
+            };
+
+            let mut levels = skill_resource.levels.iter().collect::<Vec<_>>();
+            levels.sort_by_key(|level| level.level);
+            let mut previous_total = 0;
+            let table = levels
+                .into_iter()
+                .map(|level| {
+                    let total = level.total_exp_required as i64;
+                    let exp = total - previous_total;
+                    previous_total = total;
+                    exp
+                })
+                .collect::<Vec<_>>();
+
+            let max_level = if skill == Skill::Farming {
+                skill_resource.max_level - MAX_FARMING_CAP_UPGRADE
+            } else {
+                skill_resource.max_level
+            };
+
+            tables.skill_max_levels.insert(skill, max_level);
+            tables.skills.insert(skill, table);
+        }
+
+        tables
+    }
+
+    /// Replace the pet rarities with the ones in a pets resource, which maps each pet type to an
+    /// object keyed by the rarities it can be, as in NEU's `petnums.json`. Keys that aren't
+    /// rarities are ignored.
+    pub fn with_pets_resource(mut self, resource: &Value) -> LevelingTables {
+        let Some(pets) = resource.as_object() else {
+            return self;
+        };
+
+        self.pet_rarities = pets
+            .iter()
+            .filter_map(|(pet_type, rarities)| {
+                let rarities = rarities
+                    .as_object()?
+                    .keys()
+                    .map(|rarity| Rarity::from(rarity.as_str()))
+                    .filter(|rarity| *rarity != Rarity::Unknown);
+                let (min, max) = rarities.fold(None, |range, rarity| match range {
+                    None => Some((rarity, rarity)),
+                    Some((min, max)) => Some((rarity.min(min), rarity.max(max))),
+                })?;
+                Some((pet_type.clone(), (min, max)))
+            })
+            .collect();
+        self
+    }
+
+    pub fn get_skill_table(&self, skill: Skill) -> &[i64] {
+        self.skills.get(&skill).map_or(&[], Vec::as_slice)
+    }
+
+    /// Get the max level of a skill, not including the farming level cap upgrade
+    pub fn get_skill_max_level(&self, skill: Skill) -> i64 {
+        self.skill_max_levels
+            .get(&skill)
+            .copied()
+            .unwrap_or(self.get_skill_table(skill).len() as i64)
+    }
+
+    pub fn get_catacombs_max_level(&self) -> i64 {
+        self.catacombs.len() as i64
+    }
+
+    pub fn get_hotm_max_level(&self) -> i64 {
+        self.hotm.len() as i64
+    }
+
+    pub fn get_slayer_table(&self, slayer: Slayer) -> &[i64] {
+        self.slayers.get(&slayer).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn get_slayer_max_level(&self, slayer: Slayer) -> i64 {
+        self.get_slayer_table(slayer).len() as i64
+    }
+
+    /// Get the offset into the pet table of a rarity. Rarities added after the table was written
+    /// level like legendary.
+    pub fn get_pet_rarity_offset(&self, rarity: Rarity) -> i64 {
+        self.pet_rarity_offsets
+            .get(&rarity)
+            .or_else(|| self.pet_rarity_offsets.get(&Rarity::Legendary))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn get_pet_max_level(&self, pet_type: &str) -> i64 {
+        self.pet_max_levels.get(pet_type).copied().unwrap_or(100)
+    }
+}
@@ -1,6 +1,19 @@
+pub mod auction_query;
 pub mod constants;
 pub mod error;
+pub mod formatting;
 pub mod generic_json;
+pub mod item_catalog;
+pub mod item_value;
+pub mod leveling_tables;
 pub mod minecraft;
+pub mod nbt_tag;
+pub mod networth;
+pub mod price_tracker;
+pub mod skyblock_calendar;
+pub mod skyblock_collection;
+pub mod skyblock_item;
+pub mod skyblock_member;
 pub mod skyblock_profile;
 pub mod utils;
+pub mod weight;
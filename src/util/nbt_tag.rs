@@ -0,0 +1,619 @@
+use super::error::Error;
+use base64::{engine::general_purpose, Engine};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde_json::{Map, Value};
+use std::io::{Read, Write};
+
+/// Deeply nested data is almost always malicious, so decoding stops past this depth. Items nest
+/// less than 20 deep, and this stays well within a 2 MiB thread stack in debug builds.
+const MAX_DEPTH: usize = 128;
+/// Real inventories decompress to a few hundred kilobytes, so anything past this is a gzip bomb
+const MAX_DECOMPRESSED_SIZE: u64 = 16 * 1024 * 1024;
+
+const TAG_END: u8 = 0;
+const TAG_BYTE: u8 = 1;
+const TAG_SHORT: u8 = 2;
+const TAG_INT: u8 = 3;
+const TAG_LONG: u8 = 4;
+const TAG_FLOAT: u8 = 5;
+const TAG_DOUBLE: u8 = 6;
+const TAG_BYTE_ARRAY: u8 = 7;
+const TAG_STRING: u8 = 8;
+const TAG_LIST: u8 = 9;
+const TAG_COMPOUND: u8 = 10;
+const TAG_INT_ARRAY: u8 = 11;
+const TAG_LONG_ARRAY: u8 = 12;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NbtTag {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(Vec<i8>),
+    String(String),
+    List(Vec<NbtTag>),
+    Compound(NbtCompound),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+}
+
+/// A compound tag. Entries keep the order they were decoded or inserted in.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NbtCompound(Vec<(String, NbtTag)>);
+
+/// The named root compound of an NBT document
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NbtRoot {
+    pub name: String,
+    pub compound: NbtCompound,
+}
+
+impl NbtTag {
+    fn get_type_id(&self) -> u8 {
+        match self {
+            NbtTag::Byte(_) => TAG_BYTE,
+            NbtTag::Short(_) => TAG_SHORT,
+            NbtTag::Int(_) => TAG_INT,
+            NbtTag::Long(_) => TAG_LONG,
+            NbtTag::Float(_) => TAG_FLOAT,
+            NbtTag::Double(_) => TAG_DOUBLE,
+            NbtTag::ByteArray(_) => TAG_BYTE_ARRAY,
+            NbtTag::String(_) => TAG_STRING,
+            NbtTag::List(_) => TAG_LIST,
+            NbtTag::Compound(_) => TAG_COMPOUND,
+            NbtTag::IntArray(_) => TAG_INT_ARRAY,
+            NbtTag::LongArray(_) => TAG_LONG_ARRAY,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            NbtTag::Byte(v) => Some(v as i64),
+            NbtTag::Short(v) => Some(v as i64),
+            NbtTag::Int(v) => Some(v as i64),
+            NbtTag::Long(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            NbtTag::Float(v) => Some(v as f64),
+            NbtTag::Double(v) => Some(v),
+            _ => self.as_i64().map(|v| v as f64),
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        if let NbtTag::String(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&Vec<NbtTag>> {
+        if let NbtTag::List(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_compound(&self) -> Option<&NbtCompound> {
+        if let NbtTag::Compound(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_byte_array(&self) -> Option<&Vec<i8>> {
+        if let NbtTag::ByteArray(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    /// Convert to JSON. Tag types are lost, so numbers become JSON numbers and arrays become lists.
+    pub fn to_json(&self) -> Value {
+        match self {
+            NbtTag::Byte(v) => Value::from(*v),
+            NbtTag::Short(v) => Value::from(*v),
+            NbtTag::Int(v) => Value::from(*v),
+            NbtTag::Long(v) => Value::from(*v),
+            NbtTag::Float(v) => Value::from(*v),
+            NbtTag::Double(v) => Value::from(*v),
+            NbtTag::ByteArray(v) => Value::from(v.clone()),
+            NbtTag::String(v) => Value::from(v.clone()),
+            NbtTag::List(v) => Value::Array(v.iter().map(NbtTag::to_json).collect()),
+            NbtTag::Compound(v) => v.to_json(),
+            NbtTag::IntArray(v) => Value::from(v.clone()),
+            NbtTag::LongArray(v) => Value::from(v.clone()),
+        }
+    }
+
+    /// Convert from JSON. Since JSON loses the tag types, they are restored from the names
+    /// Minecraft and Hypixel use for the key. This is lossy: `Count` and `Unbreakable` become
+    /// bytes, `id`, `Damage` and `lvl` become shorts, `timestamp` becomes a long, other integers
+    /// become ints or longs, fractions become doubles, booleans become bytes, lists under keys
+    /// ending in `_data` become byte arrays, and other arrays become lists.
+    pub fn from_json(key: &str, value: &Value) -> Result<NbtTag, Error> {
+        Ok(match value {
+            Value::Bool(v) => NbtTag::Byte(*v as i8),
+            Value::Number(v) => {
+                if let Some(int) = v.as_i64() {
+                    match key {
+                        "Count" | "Unbreakable" => NbtTag::Byte(int as i8),
+                        "id" | "Damage" | "lvl" => NbtTag::Short(int as i16),
+                        "timestamp" => NbtTag::Long(int),
+                        _ => i32::try_from(int).map_or(NbtTag::Long(int), NbtTag::Int),
+                    }
+                } else {
+                    NbtTag::Double(v.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(v) => NbtTag::String(v.clone()),
+            Value::Array(v) if key.ends_with("_data") => NbtTag::ByteArray(
+                v.iter()
+                    .map(|byte| byte.as_i64().unwrap_or_default() as i8)
+                    .collect(),
+            ),
+            Value::Array(v) => {
+                let mut list = v
+                    .iter()
+                    .map(|ele| NbtTag::from_json(key, ele))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                // NBT lists must be homogeneous, so widen mixed integers to the largest type
+                if list.iter().any(|ele| matches!(ele, NbtTag::Long(_))) {
+                    list = list
+                        .into_iter()
+                        .map(|ele| match ele {
+                            NbtTag::Int(int) => NbtTag::Long(int as i64),
+                            _ => ele,
+                        })
+                        .collect();
+                }
+                NbtTag::List(list)
+            }
+            Value::Object(v) => NbtTag::Compound(NbtCompound::from_json(v)?),
+            Value::Null => {
+                return Err(Error::Nbt(format!(
+                    "Cannot encode null value for {key} as NBT"
+                )))
+            }
+        })
+    }
+}
+
+impl NbtCompound {
+    pub fn new() -> NbtCompound {
+        NbtCompound::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&NbtTag> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut NbtTag> {
+        self.0.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Get a nested tag by a dotted path such as `tag.ExtraAttributes.id`. List elements are
+    /// addressed by their index.
+    pub fn get_property(&self, full_path: &str) -> Option<&NbtTag> {
+        let mut paths = full_path.split('.');
+        let mut cur_tag = self.get(paths.next()?)?;
+
+        for path in paths {
+            cur_tag = match cur_tag {
+                NbtTag::Compound(compound) => compound.get(path)?,
+                NbtTag::List(list) => list.get(path.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+
+        Some(cur_tag)
+    }
+
+    pub fn get_str_property(&self, full_path: &str) -> Option<&str> {
+        self.get_property(full_path).and_then(NbtTag::as_str)
+    }
+
+    pub fn get_string_property(&self, full_path: &str) -> Option<String> {
+        self.get_str_property(full_path).map(ToString::to_string)
+    }
+
+    pub fn get_int_property(&self, full_path: &str) -> Option<i64> {
+        self.get_property(full_path).and_then(NbtTag::as_i64)
+    }
+
+    pub fn get_list_property(&self, full_path: &str) -> Option<&Vec<NbtTag>> {
+        self.get_property(full_path).and_then(NbtTag::as_list)
+    }
+
+    pub fn get_compound_property(&self, full_path: &str) -> Option<&NbtCompound> {
+        self.get_property(full_path).and_then(NbtTag::as_compound)
+    }
+
+    /// Insert the tag, replacing an existing tag with the same key in place
+    pub fn insert(&mut self, key: impl Into<String>, tag: NbtTag) {
+        let key = key.into();
+        match self.0.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = tag,
+            None => self.0.push((key, tag)),
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<NbtTag> {
+        self.0
+            .iter()
+            .position(|(k, _)| k == key)
+            .map(|idx| self.0.remove(idx).1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &NbtTag)> {
+        self.0.iter().map(|(k, v)| (k, v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn to_json(&self) -> Value {
+        Value::Object(
+            self.0
+                .iter()
+                .map(|(k, v)| (k.clone(), v.to_json()))
+                .collect(),
+        )
+    }
+
+    pub fn from_json(value: &Map<String, Value>) -> Result<NbtCompound, Error> {
+        value
+            .iter()
+            .filter(|(_, ele)| !ele.is_null())
+            .map(|(key, ele)| NbtTag::from_json(key, ele).map(|tag| (key.clone(), tag)))
+            .collect::<Result<_, _>>()
+            .map(NbtCompound)
+    }
+}
+
+impl NbtRoot {
+    /// Decode base64 NBT, which may be gzipped or uncompressed
+    pub fn from_base64(data: &str) -> Result<NbtRoot, Error> {
+        NbtRoot::from_bytes(&general_purpose::STANDARD.decode(data)?)
+    }
+
+    /// Decode NBT bytes, which may be gzipped or uncompressed
+    pub fn from_bytes(bytes: &[u8]) -> Result<NbtRoot, Error> {
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut decompressed = Vec::new();
+            GzDecoder::new(bytes)
+                .take(MAX_DECOMPRESSED_SIZE + 1)
+                .read_to_end(&mut decompressed)
+                .map_err(Error::Gzip)?;
+            if decompressed.len() as u64 > MAX_DECOMPRESSED_SIZE {
+                return Err(Error::Nbt(format!(
+                    "Decompressed data exceeds the maximum of {MAX_DECOMPRESSED_SIZE} bytes"
+                )));
+            }
+            NbtRoot::from_uncompressed_bytes(&decompressed)
+        } else {
+            NbtRoot::from_uncompressed_bytes(bytes)
+        }
+    }
+
+    fn from_uncompressed_bytes(bytes: &[u8]) -> Result<NbtRoot, Error> {
+        let mut reader = NbtReader { bytes, pos: 0 };
+
+        let type_id = reader.read_u8()?;
+        if type_id != TAG_COMPOUND {
+            return Err(Error::Nbt(format!(
+                "Expected a root compound but found tag type {type_id}"
+            )));
+        }
+
+        let name = reader.read_string()?;
+        let compound = reader.read_compound(0)?;
+        if reader.pos != bytes.len() {
+            return Err(Error::Nbt(format!(
+                "Found {} trailing bytes after the root compound",
+                bytes.len() - reader.pos
+            )));
+        }
+
+        Ok(NbtRoot { name, compound })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&NbtTag> {
+        self.compound.get(key)
+    }
+
+    /// Encode as uncompressed NBT. Fails if a string is too long or a list mixes tag types.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![TAG_COMPOUND];
+        write_string(&mut bytes, &self.name)?;
+        write_compound(&mut bytes, &self.compound)?;
+        Ok(bytes)
+    }
+
+    pub fn to_gzip_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&self.to_bytes()?)?;
+        Ok(encoder.finish()?)
+    }
+
+    /// Encode as base64 gzipped NBT, the format Hypixel uses
+    pub fn to_base64(&self) -> Result<String, Error> {
+        self.to_gzip_bytes()
+            .map(|bytes| general_purpose::STANDARD.encode(bytes))
+    }
+}
+
+struct NbtReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> NbtReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if len > self.bytes.len() - self.pos {
+            return Err(Error::Nbt(format!(
+                "Unexpected end of data at byte {}",
+                self.pos
+            )));
+        }
+
+        let slice = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_i16(&mut self) -> Result<i16, Error> {
+        Ok(i16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn read_i32(&mut self) -> Result<i32, Error> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn read_i64(&mut self) -> Result<i64, Error> {
+        Ok(i64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn read_length(&mut self) -> Result<usize, Error> {
+        let pos = self.pos;
+        usize::try_from(self.read_i32()?)
+            .map_err(|_| Error::Nbt(format!("Negative length at byte {pos}")))
+    }
+
+    fn read_string(&mut self) -> Result<String, Error> {
+        let len = self.read_i16()? as u16 as usize;
+        let pos = self.pos;
+        decode_modified_utf8(self.take(len)?)
+            .ok_or_else(|| Error::Nbt(format!("Invalid string at byte {pos}")))
+    }
+
+    fn read_compound(&mut self, depth: usize) -> Result<NbtCompound, Error> {
+        let mut compound = NbtCompound::new();
+        loop {
+            let type_id = self.read_u8()?;
+            if type_id == TAG_END {
+                return Ok(compound);
+            }
+
+            let key = self.read_string()?;
+            let tag = self.read_tag(type_id, depth + 1)?;
+            compound.0.push((key, tag));
+        }
+    }
+
+    fn read_tag(&mut self, type_id: u8, depth: usize) -> Result<NbtTag, Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error::Nbt(format!(
+                "Exceeded the maximum depth of {MAX_DEPTH}"
+            )));
+        }
+
+        Ok(match type_id {
+            TAG_BYTE => NbtTag::Byte(self.read_u8()? as i8),
+            TAG_SHORT => NbtTag::Short(self.read_i16()?),
+            TAG_INT => NbtTag::Int(self.read_i32()?),
+            TAG_LONG => NbtTag::Long(self.read_i64()?),
+            TAG_FLOAT => NbtTag::Float(f32::from_bits(self.read_i32()? as u32)),
+            TAG_DOUBLE => NbtTag::Double(f64::from_bits(self.read_i64()? as u64)),
+            TAG_BYTE_ARRAY => {
+                let len = self.read_length()?;
+                NbtTag::ByteArray(self.take(len)?.iter().map(|&b| b as i8).collect())
+            }
+            TAG_STRING => NbtTag::String(self.read_string()?),
+            TAG_LIST => {
+                let element_type = self.read_u8()?;
+                let len = self.read_length()?;
+                if element_type == TAG_END && len > 0 {
+                    return Err(Error::Nbt(format!(
+                        "Non-empty list without an element type at byte {}",
+                        self.pos
+                    )));
+                }
+
+                let mut list = Vec::new();
+                for _ in 0..len {
+                    list.push(self.read_tag(element_type, depth + 1)?);
+                }
+                NbtTag::List(list)
+            }
+            TAG_COMPOUND => NbtTag::Compound(self.read_compound(depth)?),
+            TAG_INT_ARRAY => {
+                let len = self.read_length()?;
+                let mut array = Vec::new();
+                for _ in 0..len {
+                    array.push(self.read_i32()?);
+                }
+                NbtTag::IntArray(array)
+            }
+            TAG_LONG_ARRAY => {
+                let len = self.read_length()?;
+                let mut array = Vec::new();
+                for _ in 0..len {
+                    array.push(self.read_i64()?);
+                }
+                NbtTag::LongArray(array)
+            }
+            _ => {
+                return Err(Error::Nbt(format!(
+                    "Invalid tag type {type_id} at byte {}",
+                    self.pos
+                )))
+            }
+        })
+    }
+}
+
+fn write_string(bytes: &mut Vec<u8>, value: &str) -> Result<(), Error> {
+    let encoded = encode_modified_utf8(value);
+    let len = u16::try_from(encoded.len()).map_err(|_| {
+        Error::Nbt(format!(
+            "String of {} bytes exceeds the maximum of {} bytes",
+            encoded.len(),
+            u16::MAX
+        ))
+    })?;
+    bytes.extend(len.to_be_bytes());
+    bytes.extend(encoded);
+    Ok(())
+}
+
+fn write_length(bytes: &mut Vec<u8>, len: usize) -> Result<(), Error> {
+    let len = i32::try_from(len).map_err(|_| {
+        Error::Nbt(format!(
+            "Length of {len} exceeds the maximum of {}",
+            i32::MAX
+        ))
+    })?;
+    bytes.extend(len.to_be_bytes());
+    Ok(())
+}
+
+fn write_compound(bytes: &mut Vec<u8>, compound: &NbtCompound) -> Result<(), Error> {
+    for (key, tag) in compound.iter() {
+        bytes.push(tag.get_type_id());
+        write_string(bytes, key)?;
+        write_tag(bytes, tag)?;
+    }
+    bytes.push(TAG_END);
+    Ok(())
+}
+
+fn write_tag(bytes: &mut Vec<u8>, tag: &NbtTag) -> Result<(), Error> {
+    match tag {
+        NbtTag::Byte(v) => bytes.push(*v as u8),
+        NbtTag::Short(v) => bytes.extend(v.to_be_bytes()),
+        NbtTag::Int(v) => bytes.extend(v.to_be_bytes()),
+        NbtTag::Long(v) => bytes.extend(v.to_be_bytes()),
+        NbtTag::Float(v) => bytes.extend(v.to_be_bytes()),
+        NbtTag::Double(v) => bytes.extend(v.to_be_bytes()),
+        NbtTag::ByteArray(v) => {
+            write_length(bytes, v.len())?;
+            bytes.extend(v.iter().map(|&b| b as u8));
+        }
+        NbtTag::String(v) => write_string(bytes, v)?,
+        NbtTag::List(v) => {
+            let element_type = v.first().map_or(TAG_END, NbtTag::get_type_id);
+            if let Some(ele) = v.iter().find(|ele| ele.get_type_id() != element_type) {
+                return Err(Error::Nbt(format!(
+                    "List mixes tag types {element_type} and {}",
+                    ele.get_type_id()
+                )));
+            }
+
+            bytes.push(element_type);
+            write_length(bytes, v.len())?;
+            for ele in v {
+                write_tag(bytes, ele)?;
+            }
+        }
+        NbtTag::Compound(v) => write_compound(bytes, v)?,
+        NbtTag::IntArray(v) => {
+            write_length(bytes, v.len())?;
+            for ele in v {
+                bytes.extend(ele.to_be_bytes());
+            }
+        }
+        NbtTag::LongArray(v) => {
+            write_length(bytes, v.len())?;
+            for ele in v {
+                bytes.extend(ele.to_be_bytes());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Java writes strings as modified UTF-8, which encodes NUL as two bytes and characters outside
+/// the BMP as surrogate pairs
+fn decode_modified_utf8(bytes: &[u8]) -> Option<String> {
+    if let Ok(value) = std::str::from_utf8(bytes) {
+        return Some(value.to_string());
+    }
+
+    let mut utf16 = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i] as u16;
+        if b & 0x80 == 0 {
+            utf16.push(b);
+            i += 1;
+        } else if b & 0xe0 == 0xc0 {
+            let b2 = *bytes.get(i + 1)? as u16;
+            utf16.push(((b & 0x1f) << 6) | (b2 & 0x3f));
+            i += 2;
+        } else if b & 0xf0 == 0xe0 {
+            let b2 = *bytes.get(i + 1)? as u16;
+            let b3 = *bytes.get(i + 2)? as u16;
+            utf16.push(((b & 0x0f) << 12) | ((b2 & 0x3f) << 6) | (b3 & 0x3f));
+            i += 3;
+        } else {
+            return None;
+        }
+    }
+
+    String::from_utf16(&utf16).ok()
+}
+
+fn encode_modified_utf8(value: &str) -> Vec<u8> {
+    if !value.chars().any(|c| c == '\0' || c as u32 > 0xffff) {
+        return value.as_bytes().to_vec();
+    }
+
+    let mut bytes = Vec::with_capacity(value.len());
+    for unit in value.encode_utf16() {
+        match unit {
+            0x0001..=0x007f => bytes.push(unit as u8),
+            0x0000 | 0x0080..=0x07ff => {
+                bytes.push((0xc0 | (unit >> 6)) as u8);
+                bytes.push((0x80 | (unit & 0x3f)) as u8);
+            }
+            _ => {
+                bytes.push((0xe0 | (unit >> 12)) as u8);
+                bytes.push((0x80 | ((unit >> 6) & 0x3f)) as u8);
+                bytes.push((0x80 | (unit & 0x3f)) as u8);
+            }
+        }
+    }
+    bytes
+}
@@ -0,0 +1,288 @@
+use super::{
+    error::Error,
+    item_value::{ItemValuator, ItemValue, ValueComponent},
+    skyblock_item::SkyblockItem,
+    skyblock_profile::SkyblockProfile,
+};
+use crate::{
+    response::skyblock::{
+        skyblock_auctions_response::SkyblockAuction,
+        skyblock_bazaar_response::SkyblockBazaarResponse,
+    },
+    RsPixel,
+};
+use std::collections::HashMap;
+
+/// Prices of items keyed by canonical id (see [`SkyblockItem::get_canonical_id`])
+pub trait PriceSource {
+    /// Get the price of one of the item
+    fn get_price(&self, item_id: &str) -> Option<f64>;
+}
+
+impl PriceSource for HashMap<String, f64> {
+    fn get_price(&self, item_id: &str) -> Option<f64> {
+        self.get(item_id).copied()
+    }
+}
+
+/// Use the first source, falling back to the second
+impl<A: PriceSource, B: PriceSource> PriceSource for (A, B) {
+    fn get_price(&self, item_id: &str) -> Option<f64> {
+        self.0
+            .get_price(item_id)
+            .or_else(|| self.1.get_price(item_id))
+    }
+}
+
+/// Prices from the instant sell price of bazaar products
+#[derive(Debug, Clone, Default)]
+pub struct BazaarPriceSource {
+    prices: HashMap<String, f64>,
+}
+
+impl BazaarPriceSource {
+    pub fn from_response(response: &SkyblockBazaarResponse) -> BazaarPriceSource {
+        BazaarPriceSource {
+            prices: response
+                .products
+                .iter()
+                .map(|(id, product)| (id.clone(), product.quick_status.sell_price))
+                .collect(),
+        }
+    }
+
+    pub async fn fetch(rs_pixel: &mut RsPixel) -> Result<BazaarPriceSource, Error> {
+        let response = rs_pixel.get_skyblock_bazaar().await?;
+        Ok(BazaarPriceSource::from_response(&response))
+    }
+}
+
+impl PriceSource for BazaarPriceSource {
+    fn get_price(&self, item_id: &str) -> Option<f64> {
+        self.prices.get(item_id).copied()
+    }
+}
+
+/// Prices from the lowest active BIN auction of each item
+#[derive(Debug, Clone, Default)]
+pub struct LowestBinPriceSource {
+    prices: HashMap<String, f64>,
+}
+
+impl LowestBinPriceSource {
+    pub fn new() -> LowestBinPriceSource {
+        LowestBinPriceSource::default()
+    }
+
+    /// Add BIN auctions to the index. Auctions that aren't BIN or can't be decoded are skipped.
+    pub fn insert_auctions(&mut self, auctions: &[SkyblockAuction]) {
+        for auction in auctions {
+            if !auction.bin || auction.claimed {
+                continue;
+            }
+
+            if let Some(item) = auction.get_nbt() {
+                if let Some(item_id) = item.get_canonical_id() {
+                    let price = auction.starting_bid as f64 / item.count.max(1) as f64;
+                    let lowest = self.prices.entry(item_id).or_insert(price);
+                    *lowest = lowest.min(price);
+                }
+            }
+        }
+    }
+
+    /// Build the index from every page of active auctions
+    pub async fn fetch(rs_pixel: &mut RsPixel) -> Result<LowestBinPriceSource, Error> {
+        let mut source = LowestBinPriceSource::new();
+        let mut page = 0;
+
+        loop {
+            let response = rs_pixel.get_skyblock_auctions(page).await?;
+            source.insert_auctions(&response.auctions);
+
+            page += 1;
+            if page >= response.total_pages {
+                break;
+            }
+        }
+
+        Ok(source)
+    }
+}
+
+impl PriceSource for LowestBinPriceSource {
+    fn get_price(&self, item_id: &str) -> Option<f64> {
+        self.prices.get(item_id).copied()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NetworthCategory {
+    Purse,
+    Bank,
+    Inventory,
+    EnderChest,
+    Storage,
+    Wardrobe,
+    Equipment,
+    Armor,
+    TalismanBag,
+    PersonalVault,
+    Pets,
+    Sacks,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworthItem {
+    pub item_id: String,
+    /// Display name without formatting codes
+    pub name: String,
+    pub count: i64,
+    /// Value of the whole stack, including upgrades
+    pub value: f64,
+    /// Upgrades that add to the value
+    pub components: Vec<ValueComponent>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NetworthCategoryBreakdown {
+    pub total: f64,
+    /// Priced items, most valuable first
+    pub items: Vec<NetworthItem>,
+    /// Ids of items the price source has no price for
+    pub unpriced: Vec<String>,
+}
+
+impl NetworthCategoryBreakdown {
+    fn add_coins(&mut self, name: &str, coins: f64) {
+        self.total += coins;
+        self.items.push(NetworthItem {
+            item_id: "COINS".to_string(),
+            name: name.to_string(),
+            count: 1,
+            value: coins,
+            components: Vec::new(),
+        });
+    }
+
+    fn add_value(&mut self, value: ItemValue) {
+        if value.base_price.is_none() {
+            self.unpriced.push(value.item_id);
+            return;
+        }
+
+        self.total += value.total;
+        self.items.push(NetworthItem {
+            item_id: value.item_id,
+            name: value.name,
+            count: value.count,
+            value: value.total,
+            components: value.components,
+        });
+    }
+
+    fn add_items(&mut self, items: &[Option<SkyblockItem>], valuator: &ItemValuator) {
+        for item in items.iter().flatten() {
+            if let Some(value) = valuator.value_item(item) {
+                self.add_value(value);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Networth {
+    pub total: f64,
+    pub categories: HashMap<NetworthCategory, NetworthCategoryBreakdown>,
+}
+
+impl Networth {
+    pub fn get_category_total(&self, category: NetworthCategory) -> f64 {
+        self.categories
+            .get(&category)
+            .map_or(0.0, |breakdown| breakdown.total)
+    }
+}
+
+impl SkyblockProfile {
+    /// Get the networth of a member with the default item valuation. Inventories that are missing
+    /// (e.g. the API setting is off) are left out, and the bank includes the shared profile bank.
+    pub fn get_networth(&self, uuid: &str, prices: &impl PriceSource) -> Networth {
+        self.get_networth_with(uuid, &ItemValuator::new(prices))
+    }
+
+    pub fn get_networth_with(&self, uuid: &str, valuator: &ItemValuator) -> Networth {
+        let mut categories = HashMap::new();
+
+        if let Some(purse) = self.get_purse_coins(uuid) {
+            let mut breakdown = NetworthCategoryBreakdown::default();
+            breakdown.add_coins("Purse", purse);
+            categories.insert(NetworthCategory::Purse, breakdown);
+        }
+
+        let mut bank = NetworthCategoryBreakdown::default();
+        if let Some(banking) = &self.banking {
+            bank.add_coins("Bank", banking.balance);
+        }
+        if let Some(bank_account) = self.get_bank_account(uuid) {
+            bank.add_coins("Personal Bank", bank_account);
+        }
+        if !bank.items.is_empty() {
+            categories.insert(NetworthCategory::Bank, bank);
+        }
+
+        for (category, items) in [
+            (NetworthCategory::Inventory, self.get_inventory(uuid)),
+            (NetworthCategory::EnderChest, self.get_ender_chest(uuid)),
+            (NetworthCategory::Wardrobe, self.get_wardrobe(uuid)),
+            (NetworthCategory::Equipment, self.get_equippment(uuid)),
+            (NetworthCategory::Armor, self.get_armor(uuid)),
+            (NetworthCategory::TalismanBag, self.get_talisman_bag(uuid)),
+            (
+                NetworthCategory::PersonalVault,
+                self.get_personal_vault(uuid),
+            ),
+        ] {
+            if let Some(items) = items {
+                let mut breakdown = NetworthCategoryBreakdown::default();
+                breakdown.add_items(&items, valuator);
+                categories.insert(category, breakdown);
+            }
+        }
+
+        if let Some(storage) = self.get_storage(uuid) {
+            let mut breakdown = NetworthCategoryBreakdown::default();
+            for items in storage.values() {
+                breakdown.add_items(items, valuator);
+            }
+            categories.insert(NetworthCategory::Storage, breakdown);
+        }
+
+        if let Some(pets) = self.get_pets(uuid) {
+            let mut breakdown = NetworthCategoryBreakdown::default();
+            for pet in &pets {
+                breakdown.add_value(valuator.value_pet(pet));
+            }
+            categories.insert(NetworthCategory::Pets, breakdown);
+        }
+
+        if let Some(sacks) = self.get_sacks(uuid) {
+            let mut breakdown = NetworthCategoryBreakdown::default();
+            for (item_id, count) in sacks {
+                if count > 0 {
+                    breakdown.add_value(valuator.value_stack(item_id, count));
+                }
+            }
+            categories.insert(NetworthCategory::Sacks, breakdown);
+        }
+
+        for breakdown in categories.values_mut() {
+            breakdown.items.sort_by(|a, b| b.value.total_cmp(&a.value));
+        }
+
+        Networth {
+            total: categories.values().map(|breakdown| breakdown.total).sum(),
+            categories,
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use super::{error::Error, utils::get_timestamp_millis};
+use crate::{
+    response::skyblock::skyblock_auctions_ended_response::SkyblockAuctionsEndedResponse, RsPixel,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::PathBuf,
+    time::Duration,
+};
+
+/// How often Hypixel refreshes the ended auctions endpoint
+const AUCTIONS_ENDED_UPDATE_INTERVAL: i64 = 60_000;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TrackedSale {
+    pub auction_id: String,
+    pub item_id: String,
+    /// Price of the whole stack
+    pub price: i64,
+    pub count: i64,
+    pub timestamp: i64,
+    pub bin: bool,
+}
+
+impl TrackedSale {
+    pub fn get_price_per_unit(&self) -> f64 {
+        self.price as f64 / self.count.max(1) as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriceWindow {
+    Hour,
+    Day,
+    Week,
+}
+
+impl PriceWindow {
+    pub fn get_duration_millis(&self) -> i64 {
+        match self {
+            Self::Hour => 3_600_000,
+            Self::Day => 86_400_000,
+            Self::Week => 604_800_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceStats {
+    pub item_id: String,
+    pub window: PriceWindow,
+    /// Number of auctions sold in the window
+    pub sales: i64,
+    /// Number of items sold in the window
+    pub volume: i64,
+    /// Average price per item
+    pub average: f64,
+    /// Lowest price per item
+    pub min: f64,
+    /// Highest price per item
+    pub max: f64,
+}
+
+/// Backing store for sales seen by a [`PriceTracker`]
+pub trait SaleStorage {
+    fn contains(&self, auction_id: &str) -> bool;
+
+    fn insert(&mut self, sale: TrackedSale) -> Result<(), Error>;
+
+    fn get_sales(&self, item_id: &str) -> &[TrackedSale];
+
+    fn get_item_ids(&self) -> Vec<&str>;
+
+    /// Remove all sales that happened before the timestamp
+    fn remove_before(&mut self, timestamp: i64) -> Result<(), Error>;
+
+    /// Persist pending changes. Called after each batch of inserts.
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct InMemorySaleStorage {
+    sales: HashMap<String, Vec<TrackedSale>>,
+    #[serde(skip)]
+    auction_ids: HashSet<String>,
+}
+
+impl InMemorySaleStorage {
+    pub fn new() -> InMemorySaleStorage {
+        InMemorySaleStorage::default()
+    }
+
+    fn index_auction_ids(&mut self) {
+        self.auction_ids = self
+            .sales
+            .values()
+            .flatten()
+            .map(|sale| sale.auction_id.clone())
+            .collect();
+    }
+}
+
+impl SaleStorage for InMemorySaleStorage {
+    fn contains(&self, auction_id: &str) -> bool {
+        self.auction_ids.contains(auction_id)
+    }
+
+    fn insert(&mut self, sale: TrackedSale) -> Result<(), Error> {
+        if self.auction_ids.insert(sale.auction_id.clone()) {
+            self.sales
+                .entry(sale.item_id.clone())
+                .or_default()
+                .push(sale);
+        }
+        Ok(())
+    }
+
+    fn get_sales(&self, item_id: &str) -> &[TrackedSale] {
+        self.sales.get(item_id).map_or(&[], Vec::as_slice)
+    }
+
+    fn get_item_ids(&self) -> Vec<&str> {
+        self.sales.keys().map(String::as_str).collect()
+    }
+
+    fn remove_before(&mut self, timestamp: i64) -> Result<(), Error> {
+        for sales in self.sales.values_mut() {
+            sales.retain(|sale| sale.timestamp >= timestamp);
+        }
+        self.sales.retain(|_, sales| !sales.is_empty());
+        self.index_auction_ids();
+        Ok(())
+    }
+}
+
+/// Sale storage persisted as JSON so averages survive restarts
+#[derive(Debug)]
+pub struct FileSaleStorage {
+    path: PathBuf,
+    storage: InMemorySaleStorage,
+    dirty: bool,
+}
+
+impl FileSaleStorage {
+    /// Open the storage file, loading existing sales if it exists
+    pub fn open(path: impl Into<PathBuf>) -> Result<FileSaleStorage, Error> {
+        let path = path.into();
+        let mut storage = if path.exists() {
+            serde_json::from_slice::<InMemorySaleStorage>(&fs::read(&path)?)?
+        } else {
+            InMemorySaleStorage::new()
+        };
+        storage.index_auction_ids();
+
+        Ok(FileSaleStorage {
+            path,
+            storage,
+            dirty: false,
+        })
+    }
+}
+
+impl SaleStorage for FileSaleStorage {
+    fn contains(&self, auction_id: &str) -> bool {
+        self.storage.contains(auction_id)
+    }
+
+    fn insert(&mut self, sale: TrackedSale) -> Result<(), Error> {
+        self.dirty = true;
+        self.storage.insert(sale)
+    }
+
+    fn get_sales(&self, item_id: &str) -> &[TrackedSale] {
+        self.storage.get_sales(item_id)
+    }
+
+    fn get_item_ids(&self) -> Vec<&str> {
+        self.storage.get_item_ids()
+    }
+
+    fn remove_before(&mut self, timestamp: i64) -> Result<(), Error> {
+        self.dirty = true;
+        self.storage.remove_before(timestamp)
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        if self.dirty {
+            // Write to a temporary file first so a crash never leaves a truncated file behind
+            let tmp_path = self.path.with_extension("tmp");
+            fs::write(&tmp_path, serde_json::to_vec(&self.storage)?)?;
+            fs::rename(&tmp_path, &self.path)?;
+            self.dirty = false;
+        }
+        Ok(())
+    }
+}
+
+/// Tracks historical auction prices by polling the ended auctions endpoint
+pub struct PriceTracker<S: SaleStorage> {
+    storage: S,
+    last_updated: i64,
+}
+
+impl<S: SaleStorage> PriceTracker<S> {
+    pub fn new(storage: S) -> PriceTracker<S> {
+        PriceTracker {
+            storage,
+            last_updated: 0,
+        }
+    }
+
+    pub fn get_storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Fetch the ended auctions and record new sales. Returns the number of new sales.
+    pub async fn poll(&mut self, rs_pixel: &mut RsPixel) -> Result<usize, Error> {
+        let response = rs_pixel.get_skyblock_auctions_ended().await?;
+        self.ingest(&response)
+    }
+
+    /// Poll forever, waiting for the endpoint to refresh between polls. Like
+    /// `RateLimitStrategy::Delay`, this blocks the current thread while waiting.
+    pub async fn run(&mut self, rs_pixel: &mut RsPixel) -> Result<(), Error> {
+        loop {
+            self.poll(rs_pixel).await?;
+            self.prune()?;
+
+            let time_till_next_poll = self.get_time_till_next_poll();
+            if time_till_next_poll > 0 {
+                std::thread::sleep(Duration::from_millis(time_till_next_poll as u64));
+            }
+        }
+    }
+
+    /// Record the sales from an ended auctions response. Returns the number of new sales.
+    pub fn ingest(&mut self, response: &SkyblockAuctionsEndedResponse) -> Result<usize, Error> {
+        let mut new_sales = 0;
+
+        for auction in &response.auctions {
+            if self.storage.contains(&auction.auction_id) {
+                continue;
+            }
+
+            if let Some(item) = auction.get_nbt() {
+                if let Some(item_id) = item.get_canonical_id() {
+                    self.storage.insert(TrackedSale {
+                        auction_id: auction.auction_id.clone(),
+                        item_id,
+                        price: auction.price,
+                        count: item.count,
+                        timestamp: auction.timestamp,
+                        bin: auction.bin,
+                    })?;
+                    new_sales += 1;
+                }
+            }
+        }
+
+        self.last_updated = self.last_updated.max(response.last_updated);
+        self.storage.flush()?;
+        Ok(new_sales)
+    }
+
+    /// Milliseconds until the ended auctions endpoint refreshes
+    pub fn get_time_till_next_poll(&self) -> i64 {
+        (self.last_updated + AUCTIONS_ENDED_UPDATE_INTERVAL - get_timestamp_millis()).max(0)
+    }
+
+    /// Drop sales older than the largest window
+    pub fn prune(&mut self) -> Result<(), Error> {
+        self.storage
+            .remove_before(get_timestamp_millis() - PriceWindow::Week.get_duration_millis())?;
+        self.storage.flush()
+    }
+
+    pub fn get_stats(&self, item_id: &str, window: PriceWindow) -> Option<PriceStats> {
+        self.get_stats_at(item_id, window, get_timestamp_millis())
+    }
+
+    /// Get the stats for the window ending at the timestamp
+    pub fn get_stats_at(&self, item_id: &str, window: PriceWindow, now: i64) -> Option<PriceStats> {
+        let start = now - window.get_duration_millis();
+        let mut stats = PriceStats {
+            item_id: item_id.to_string(),
+            window,
+            sales: 0,
+            volume: 0,
+            average: 0.0,
+            min: f64::MAX,
+            max: f64::MIN,
+        };
+        let mut total_price = 0;
+
+        for sale in self.storage.get_sales(item_id) {
+            if sale.timestamp < start || sale.timestamp > now {
+                continue;
+            }
+
+            let price_per_unit = sale.get_price_per_unit();
+            stats.sales += 1;
+            stats.volume += sale.count;
+            stats.min = stats.min.min(price_per_unit);
+            stats.max = stats.max.max(price_per_unit);
+            total_price += sale.price;
+        }
+
+        if stats.sales == 0 {
+            return None;
+        }
+
+        stats.average = total_price as f64 / stats.volume.max(1) as f64;
+        Some(stats)
+    }
+
+    /// Get the stats of every tracked item for the window
+    pub fn get_all_stats(&self, window: PriceWindow) -> HashMap<String, PriceStats> {
+        let now = get_timestamp_millis();
+        self.storage
+            .get_item_ids()
+            .into_iter()
+            .filter_map(|item_id| {
+                self.get_stats_at(item_id, window, now)
+                    .map(|stats| (item_id.to_string(), stats))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,79 @@
+use super::utils::get_timestamp_millis;
+use std::fmt;
+
+/// Start of SkyBlock year 1, month 1, day 1
+pub const SKYBLOCK_EPOCH_MILLIS: i64 = 1_560_275_700_000;
+/// A SkyBlock day lasts 20 minutes
+pub const SKYBLOCK_DAY_MILLIS: i64 = 1_200_000;
+pub const SKYBLOCK_MONTH_MILLIS: i64 = SKYBLOCK_DAY_MILLIS * 31;
+pub const SKYBLOCK_YEAR_MILLIS: i64 = SKYBLOCK_MONTH_MILLIS * 12;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Early Spring",
+    "Spring",
+    "Late Spring",
+    "Early Summer",
+    "Summer",
+    "Late Summer",
+    "Early Autumn",
+    "Autumn",
+    "Late Autumn",
+    "Early Winter",
+    "Winter",
+    "Late Winter",
+];
+
+/// A date on the SkyBlock calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SkyblockDate {
+    pub year: i64,
+    /// 1 to 12
+    pub month: i64,
+    /// 1 to 31
+    pub day: i64,
+}
+
+impl SkyblockDate {
+    pub fn from_timestamp(timestamp_millis: i64) -> SkyblockDate {
+        let elapsed = (timestamp_millis - SKYBLOCK_EPOCH_MILLIS).max(0);
+        SkyblockDate {
+            year: elapsed / SKYBLOCK_YEAR_MILLIS + 1,
+            month: elapsed % SKYBLOCK_YEAR_MILLIS / SKYBLOCK_MONTH_MILLIS + 1,
+            day: elapsed % SKYBLOCK_MONTH_MILLIS / SKYBLOCK_DAY_MILLIS + 1,
+        }
+    }
+
+    pub fn now() -> SkyblockDate {
+        SkyblockDate::from_timestamp(get_timestamp_millis())
+    }
+
+    /// Get the real time the date starts at
+    pub fn to_timestamp(&self) -> i64 {
+        SKYBLOCK_EPOCH_MILLIS
+            + (self.year - 1) * SKYBLOCK_YEAR_MILLIS
+            + (self.month - 1) * SKYBLOCK_MONTH_MILLIS
+            + (self.day - 1) * SKYBLOCK_DAY_MILLIS
+    }
+
+    /// Get the name of the month (e.g. `Late Spring`)
+    pub fn get_month_name(&self) -> &'static str {
+        MONTH_NAMES[(self.month - 1).clamp(0, 11) as usize]
+    }
+}
+
+impl fmt::Display for SkyblockDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}, Year {}",
+            self.get_month_name(),
+            self.day,
+            self.year
+        )
+    }
+}
+
+/// Get the current SkyBlock year
+pub fn get_current_year() -> i64 {
+    SkyblockDate::now().year
+}
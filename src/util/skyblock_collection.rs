@@ -0,0 +1,94 @@
+use super::skyblock_profile::SkyblockProfile;
+use crate::response::resources::skyblock_collections_response::SkyblockCollectionsResponse;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct SkyblockCollection {
+    /// Item id (e.g. `WHEAT`)
+    pub id: String,
+    pub name: String,
+    /// Category name (e.g. `Farming`)
+    pub category: String,
+    /// Amount collected by the member
+    pub amount: i64,
+    /// Amount collected by every member of the profile, which tiers are based on
+    pub coop_amount: i64,
+    pub tier: i64,
+    pub max_tier: i64,
+    /// `None` once the collection is maxed
+    pub amount_to_next_tier: Option<i64>,
+}
+
+impl SkyblockCollection {
+    pub fn is_maxed(&self) -> bool {
+        self.tier >= self.max_tier
+    }
+}
+
+impl SkyblockProfile {
+    /// Get every collection in the collections resource for a member, sorted by category then
+    /// name. The tier is the highest of the tier reached by the coop amount and the tiers the
+    /// member has unlocked.
+    pub fn get_collections(
+        &self,
+        uuid: &str,
+        resource: &SkyblockCollectionsResponse,
+    ) -> Option<Vec<SkyblockCollection>> {
+        let member = self.member(uuid)?;
+
+        let mut coop_amounts: HashMap<String, f64> = HashMap::new();
+        for coop_member in self
+            .get_member_uuids()
+            .into_iter()
+            .filter_map(|member_uuid| self.member(member_uuid))
+        {
+            for (id, amount) in coop_member.collection {
+                *coop_amounts.entry(id).or_insert(0.0) += amount;
+            }
+        }
+
+        let mut unlocked_tiers: HashMap<&str, i64> = HashMap::new();
+        for (id, tier) in member
+            .unlocked_coll_tiers
+            .iter()
+            .filter_map(|unlocked| unlocked.rsplit_once('_'))
+            .filter_map(|(id, tier)| tier.parse::<i64>().ok().map(|tier| (id, tier)))
+        {
+            let unlocked_tier = unlocked_tiers.entry(id).or_insert(tier);
+            *unlocked_tier = (*unlocked_tier).max(tier);
+        }
+
+        let mut collections = Vec::new();
+        for category in resource.collections.values() {
+            for (id, item) in &category.items {
+                let coop_amount = coop_amounts.get(id).copied().unwrap_or(0.0) as i64;
+                let reached_tier = item
+                    .tiers
+                    .iter()
+                    .filter(|tier| coop_amount >= tier.amount_required)
+                    .map(|tier| tier.tier)
+                    .max()
+                    .unwrap_or(0);
+                let tier = reached_tier.max(unlocked_tiers.get(id.as_str()).copied().unwrap_or(0));
+
+                collections.push(SkyblockCollection {
+                    id: id.clone(),
+                    name: item.name.clone(),
+                    category: category.name.clone(),
+                    amount: member.collection.get(id).copied().unwrap_or(0.0) as i64,
+                    coop_amount,
+                    tier,
+                    max_tier: item.max_tiers,
+                    amount_to_next_tier: item
+                        .tiers
+                        .iter()
+                        .find(|next_tier| next_tier.tier == tier + 1)
+                        .map(|next_tier| (next_tier.amount_required - coop_amount).max(0)),
+                });
+            }
+        }
+
+        collections.sort_by(|a, b| a.category.cmp(&b.category).then(a.name.cmp(&b.name)));
+        Some(collections)
+    }
+}
@@ -0,0 +1,440 @@
+use super::{
+    generic_json::Property,
+    nbt_tag::{NbtCompound, NbtTag},
+    utils::parse_items_bytes,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Gemstone slot prefixes that can hold more than one gemstone type
+const UNIVERSAL_GEMSTONE_SLOTS: &[&str] = &[
+    "COMBAT",
+    "OFFENSIVE",
+    "DEFENSIVE",
+    "MINING",
+    "UNIVERSAL",
+    "CHISEL",
+];
+
+/// Keys of the contents of backpacks and bags, checked in this order if none matches the item id
+const BACKPACK_DATA_KEYS: &[&str] = &[
+    "small_backpack_data",
+    "medium_backpack_data",
+    "large_backpack_data",
+    "greater_backpack_data",
+    "jumbo_backpack_data",
+    "new_year_cake_bag_data",
+];
+
+/// An item decoded from Hypixel's NBT data
+#[derive(Debug, Clone)]
+pub struct SkyblockItem {
+    /// Minecraft item id
+    pub id: i64,
+    pub count: i64,
+    pub damage: i64,
+    /// Display name with formatting codes
+    pub name: Option<String>,
+    /// Lore lines with formatting codes
+    pub lore: Vec<String>,
+    pub skyblock_id: Option<String>,
+    pub uuid: Option<String>,
+    pub reforge: Option<String>,
+    pub enchantments: HashMap<String, i64>,
+    pub hot_potato_count: i64,
+    pub dungeon_stars: i64,
+    pub gemstones: Vec<SkyblockGemstone>,
+    pub unlocked_gemstone_slots: Vec<String>,
+    pub recombobulated: bool,
+    pub rarity_upgrades: i64,
+    /// Creation time in milliseconds. Older items store a date string, which is left in `nbt`.
+    pub timestamp: Option<i64>,
+    pub origin_tag: Option<String>,
+    pub pet_info: Option<Value>,
+    pub backpack_contents: Option<Vec<Option<SkyblockItem>>>,
+    /// The undecoded item with its original tag types
+    pub nbt: NbtCompound,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkyblockGemstone {
+    /// Slot the gemstone is applied to (e.g. `COMBAT_0` or `JASPER_0`)
+    pub slot: String,
+    /// Type of the gemstone (e.g. `JASPER`)
+    pub gem_type: String,
+    /// Quality of the gemstone (e.g. `PERFECT`)
+    pub quality: String,
+}
+
+impl SkyblockItem {
+    /// Decode a single item. Returns `None` for empty slots.
+    pub fn from_nbt(nbt: &NbtTag) -> Option<SkyblockItem> {
+        let nbt = nbt.as_compound()?;
+        let id = nbt.get_int_property("id")?;
+        let empty = NbtCompound::new();
+        let extra_attributes = nbt
+            .get_compound_property("tag.ExtraAttributes")
+            .unwrap_or(&empty);
+
+        let rarity_upgrades = extra_attributes
+            .get_int_property("rarity_upgrades")
+            .unwrap_or(0);
+
+        Some(SkyblockItem {
+            id,
+            count: nbt.get_int_property("Count").unwrap_or(1),
+            damage: nbt.get_int_property("Damage").unwrap_or(0),
+            name: nbt.get_string_property("tag.display.Name"),
+            lore: nbt
+                .get_list_property("tag.display.Lore")
+                .map(|lore| {
+                    lore.iter()
+                        .filter_map(|line| line.as_str().map(ToString::to_string))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            skyblock_id: extra_attributes.get_string_property("id"),
+            uuid: extra_attributes.get_string_property("uuid"),
+            reforge: extra_attributes.get_string_property("modifier"),
+            enchantments: extra_attributes
+                .get_compound_property("enchantments")
+                .map(|enchantments| {
+                    enchantments
+                        .iter()
+                        .filter_map(|(name, level)| level.as_i64().map(|l| (name.clone(), l)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            hot_potato_count: extra_attributes
+                .get_int_property("hot_potato_count")
+                .unwrap_or(0),
+            dungeon_stars: extra_attributes
+                .get_int_property("upgrade_level")
+                .or_else(|| extra_attributes.get_int_property("dungeon_item_level"))
+                .unwrap_or(0),
+            gemstones: Self::parse_gemstones(extra_attributes),
+            unlocked_gemstone_slots: extra_attributes
+                .get_list_property("gems.unlocked_slots")
+                .map(|slots| {
+                    slots
+                        .iter()
+                        .filter_map(|slot| slot.as_str().map(ToString::to_string))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            recombobulated: rarity_upgrades > 0,
+            rarity_upgrades,
+            timestamp: extra_attributes.get_int_property("timestamp"),
+            origin_tag: extra_attributes.get_string_property("originTag"),
+            pet_info: extra_attributes
+                .get_str_property("petInfo")
+                .and_then(|pet_info| serde_json::from_str(pet_info).ok()),
+            backpack_contents: Self::parse_backpack_contents(extra_attributes),
+            nbt: nbt.clone(),
+        })
+    }
+
+    /// Decode a list of items, such as the `i` tag of an inventory. Empty slots are kept as `None`.
+    pub fn from_nbt_list(nbt: &NbtTag) -> Vec<Option<SkyblockItem>> {
+        nbt.as_list()
+            .map(|items| items.iter().map(SkyblockItem::from_nbt).collect())
+            .unwrap_or_default()
+    }
+
+    fn parse_gemstones(extra_attributes: &NbtCompound) -> Vec<SkyblockGemstone> {
+        let mut gemstones = Vec::new();
+
+        if let Some(gems) = extra_attributes.get_compound_property("gems") {
+            for (slot, gem) in gems.iter() {
+                if slot == "unlocked_slots" || slot.ends_with("_gem") {
+                    continue;
+                }
+
+                // Newer items store a compound with the quality and uuid instead of only the quality
+                let quality = match gem.as_str() {
+                    Some(quality) => quality,
+                    None => match gem
+                        .as_compound()
+                        .and_then(|gem| gem.get_str_property("quality"))
+                    {
+                        Some(quality) => quality,
+                        None => continue,
+                    },
+                };
+
+                let slot_type = slot.rsplit_once('_').map_or(slot.as_str(), |s| s.0);
+                let gem_type = if UNIVERSAL_GEMSTONE_SLOTS.contains(&slot_type) {
+                    match gems.get_str_property(&format!("{slot}_gem")) {
+                        Some(gem_type) => gem_type,
+                        None => continue,
+                    }
+                } else {
+                    slot_type
+                };
+
+                gemstones.push(SkyblockGemstone {
+                    slot: slot.clone(),
+                    gem_type: gem_type.to_string(),
+                    quality: quality.to_string(),
+                });
+            }
+        }
+
+        gemstones
+    }
+
+    fn parse_backpack_contents(
+        extra_attributes: &NbtCompound,
+    ) -> Option<Vec<Option<SkyblockItem>>> {
+        // Prefer the key named after the item (e.g. `jumbo_backpack_data` for `JUMBO_BACKPACK`)
+        let item_key = extra_attributes
+            .get_str_property("id")
+            .map(|id| format!("{}_data", id.to_lowercase()));
+        let key = BACKPACK_DATA_KEYS
+            .iter()
+            .find(|key| item_key.as_deref() == Some(**key) && extra_attributes.get(key).is_some())
+            .or_else(|| {
+                BACKPACK_DATA_KEYS
+                    .iter()
+                    .find(|key| extra_attributes.get(key).is_some())
+            })?;
+        let bytes = extra_attributes
+            .get(key)
+            .and_then(NbtTag::as_byte_array)?
+            .iter()
+            .map(|&b| b as u8)
+            .collect::<Vec<u8>>();
+
+        parse_items_bytes(&bytes).ok()
+    }
+
+    /// Convert the item back to its NBT form, writing the typed fields that were changed. Other
+    /// tags, including gemstones and backpack contents, are kept as they were decoded, and
+    /// changed fields keep the tag type they were decoded with.
+    pub fn to_nbt(&self) -> NbtTag {
+        let original = Self::from_nbt(&NbtTag::Compound(self.nbt.clone()));
+        let original = original.as_ref();
+        let mut nbt = self.nbt.clone();
+
+        for (key, value, original_value, default) in [
+            ("id", self.id, original.map(|o| o.id), NbtTag::Short(0)),
+            (
+                "Count",
+                self.count,
+                original.map(|o| o.count),
+                NbtTag::Byte(0),
+            ),
+            (
+                "Damage",
+                self.damage,
+                original.map(|o| o.damage),
+                NbtTag::Short(0),
+            ),
+        ] {
+            if original_value != Some(value) {
+                let tag = Self::to_int_tag(nbt.get(key), default, value);
+                nbt.insert(key, tag);
+            }
+        }
+
+        let tag = Self::get_or_insert_compound(&mut nbt, "tag");
+        let display = Self::get_or_insert_compound(tag, "display");
+        if original.is_none_or(|original| original.name != self.name) {
+            Self::set_optional(display, "Name", self.name.clone().map(NbtTag::String));
+        }
+        if original.is_none_or(|original| original.lore != self.lore) {
+            Self::set_optional(
+                display,
+                "Lore",
+                (!self.lore.is_empty())
+                    .then(|| NbtTag::List(self.lore.iter().cloned().map(NbtTag::String).collect())),
+            );
+        }
+
+        let extra_attributes = Self::get_or_insert_compound(tag, "ExtraAttributes");
+        for (key, value, original_value) in [
+            ("id", &self.skyblock_id, original.map(|o| &o.skyblock_id)),
+            ("uuid", &self.uuid, original.map(|o| &o.uuid)),
+            ("modifier", &self.reforge, original.map(|o| &o.reforge)),
+            (
+                "originTag",
+                &self.origin_tag,
+                original.map(|o| &o.origin_tag),
+            ),
+        ] {
+            if original_value != Some(value) {
+                Self::set_optional(extra_attributes, key, value.clone().map(NbtTag::String));
+            }
+        }
+
+        if original.is_none_or(|original| original.pet_info != self.pet_info) {
+            Self::set_optional(
+                extra_attributes,
+                "petInfo",
+                self.pet_info
+                    .as_ref()
+                    .map(|pet_info| NbtTag::String(pet_info.to_string())),
+            );
+        }
+
+        if original.is_none_or(|original| original.enchantments != self.enchantments) {
+            Self::write_enchantments(extra_attributes, &self.enchantments);
+        }
+
+        let stars_key = if extra_attributes.get("dungeon_item_level").is_some() {
+            "dungeon_item_level"
+        } else {
+            "upgrade_level"
+        };
+        for (key, value, original_value) in [
+            (
+                "hot_potato_count",
+                self.hot_potato_count,
+                original.map(|o| o.hot_potato_count),
+            ),
+            (
+                "rarity_upgrades",
+                self.rarity_upgrades,
+                original.map(|o| o.rarity_upgrades),
+            ),
+            (
+                stars_key,
+                self.dungeon_stars,
+                original.map(|o| o.dungeon_stars),
+            ),
+        ] {
+            if original_value != Some(value) {
+                let tag = Self::to_int_tag(extra_attributes.get(key), NbtTag::Int(0), value);
+                Self::set_optional(extra_attributes, key, (value > 0).then_some(tag));
+            }
+        }
+
+        // Legacy items store the timestamp as a date string, which is kept unless a timestamp is
+        // set
+        if original.is_none_or(|original| original.timestamp != self.timestamp) {
+            let tag = self.timestamp.map(|timestamp| {
+                Self::to_int_tag(
+                    extra_attributes.get("timestamp"),
+                    NbtTag::Long(0),
+                    timestamp,
+                )
+            });
+            Self::set_optional(extra_attributes, "timestamp", tag);
+        }
+
+        // Drop compounds that were only created for writing and stayed empty
+        for path in ["tag.display", "tag.ExtraAttributes", "tag"] {
+            if self.nbt.get_property(path).is_none()
+                && nbt
+                    .get_compound_property(path)
+                    .is_some_and(NbtCompound::is_empty)
+            {
+                match path.split_once('.') {
+                    Some((parent, key)) => {
+                        Self::get_or_insert_compound(&mut nbt, parent).remove(key)
+                    }
+                    None => nbt.remove(path),
+                };
+            }
+        }
+
+        NbtTag::Compound(nbt)
+    }
+
+    /// Update the enchantments in place so unchanged entries keep their order and tag type
+    fn write_enchantments(extra_attributes: &mut NbtCompound, enchantments: &HashMap<String, i64>) {
+        let mut compound = extra_attributes
+            .get_compound_property("enchantments")
+            .cloned()
+            .unwrap_or_default();
+
+        let removed = compound
+            .iter()
+            .filter(|(name, level)| level.as_i64().is_some() && !enchantments.contains_key(*name))
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        for name in removed {
+            compound.remove(&name);
+        }
+
+        let mut sorted = enchantments.iter().collect::<Vec<_>>();
+        sorted.sort();
+        for (name, level) in sorted {
+            let tag = Self::to_int_tag(compound.get(name), NbtTag::Int(0), *level);
+            compound.insert(name.clone(), tag);
+        }
+
+        Self::set_optional(
+            extra_attributes,
+            "enchantments",
+            (!compound.is_empty()).then_some(NbtTag::Compound(compound)),
+        );
+    }
+
+    /// Create an integer tag of the same type as the existing tag, or else of the default's type
+    fn to_int_tag(existing: Option<&NbtTag>, default: NbtTag, value: i64) -> NbtTag {
+        let tag_type = match existing {
+            Some(existing) if existing.as_i64().is_some() => existing,
+            _ => &default,
+        };
+
+        match tag_type {
+            NbtTag::Byte(_) => NbtTag::Byte(value as i8),
+            NbtTag::Short(_) => NbtTag::Short(value as i16),
+            NbtTag::Int(_) => NbtTag::Int(value as i32),
+            _ => NbtTag::Long(value),
+        }
+    }
+
+    fn get_or_insert_compound<'a>(compound: &'a mut NbtCompound, key: &str) -> &'a mut NbtCompound {
+        if compound.get_compound_property(key).is_none() {
+            compound.insert(key, NbtTag::Compound(NbtCompound::new()));
+        }
+
+        match compound.get_mut(key) {
+            Some(NbtTag::Compound(child)) => child,
+            _ => unreachable!("a compound was just inserted"),
+        }
+    }
+
+    fn set_optional(compound: &mut NbtCompound, key: &str, tag: Option<NbtTag>) {
+        match tag {
+            Some(tag) => compound.insert(key, tag),
+            None => {
+                compound.remove(key);
+            }
+        }
+    }
+
+    /// Get the canonical id of the item. Pets, single enchantment books, and runes are keyed by
+    /// their variant (e.g. `PET_ENDER_DRAGON_LEGENDARY`, `ENCHANTMENT_SHARPNESS_6`,
+    /// `RUNE_MUSIC_3`), everything else uses the SkyBlock item id.
+    pub fn get_canonical_id(&self) -> Option<String> {
+        let id = self.skyblock_id.as_deref()?;
+
+        match id {
+            "PET" => {
+                let pet_info = self.pet_info.as_ref()?;
+                Some(format!(
+                    "PET_{}_{}",
+                    pet_info.get_str_property("type")?,
+                    pet_info.get_str_property("tier")?
+                ))
+            }
+            "ENCHANTED_BOOK" if self.enchantments.len() == 1 => {
+                let (name, level) = self.enchantments.iter().next()?;
+                Some(format!("ENCHANTMENT_{}_{}", name.to_uppercase(), level))
+            }
+            "RUNE" | "UNIQUE_RUNE" => {
+                match self.nbt.get_compound_property("tag.ExtraAttributes.runes") {
+                    Some(runes) if runes.len() == 1 => {
+                        let (name, level) = runes.iter().next()?;
+                        Some(format!("RUNE_{}_{}", name.to_uppercase(), level.as_i64()?))
+                    }
+                    _ => Some(id.to_string()),
+                }
+            }
+            _ => Some(id.to_string()),
+        }
+    }
+}
@@ -0,0 +1,569 @@
+use super::{generic_json::Property, skyblock_item::SkyblockItem, utils::parse_items};
+use crate::types::{dungeon_class::DungeonClass, skill::Skill};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// A member of a SkyBlock profile, read from either the legacy or the v2 layout. Fields Hypixel
+/// adds later are available through `raw`.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockMember {
+    #[serde(deserialize_with = "or_default")]
+    pub coin_purse: f64,
+    /// Personal bank of coop members
+    #[serde(deserialize_with = "or_default")]
+    pub bank_account: Option<f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub fairy_souls_collected: i64,
+    #[serde(deserialize_with = "or_default")]
+    pub first_join: Option<i64>,
+    #[serde(deserialize_with = "or_default")]
+    pub last_save: Option<i64>,
+    #[serde(flatten)]
+    pub skills: SkyblockSkills,
+    #[serde(deserialize_with = "or_default")]
+    pub slayer_bosses: HashMap<String, SkyblockSlayerBoss>,
+    #[serde(deserialize_with = "or_default")]
+    pub dungeons: Option<SkyblockDungeons>,
+    #[serde(deserialize_with = "or_default")]
+    pub pets: Vec<SkyblockPet>,
+    #[serde(flatten)]
+    pub inventories: SkyblockInventories,
+    #[serde(deserialize_with = "or_default")]
+    pub collection: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub unlocked_coll_tiers: Vec<String>,
+    #[serde(deserialize_with = "or_default")]
+    pub stats: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub objectives: HashMap<String, SkyblockObjective>,
+    #[serde(deserialize_with = "or_default")]
+    pub mining_core: Option<SkyblockMiningCore>,
+    #[serde(deserialize_with = "or_default")]
+    pub sacks_counts: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub crafted_generators: Vec<String>,
+    #[serde(deserialize_with = "or_default")]
+    pub jacob2: Option<SkyblockJacob>,
+    #[serde(deserialize_with = "or_default")]
+    pub leveling: Option<SkyblockLeveling>,
+    /// The member as returned by the API
+    #[serde(skip)]
+    pub raw: Value,
+}
+
+impl SkyblockMember {
+    /// Parse a member in either layout
+    pub fn from_raw(raw: &Value) -> Option<SkyblockMember> {
+        SkyblockMember::deserialize(to_legacy_layout(raw))
+            .ok()
+            .map(|mut member| {
+                member.raw = raw.clone();
+                member
+            })
+    }
+}
+
+/// Deserialize a field, falling back to its default if Hypixel sends an unexpected type, so one
+/// bad field does not drop the whole member
+fn or_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Value::deserialize(deserializer)
+        .ok()
+        .and_then(|value| T::deserialize(value).ok())
+        .unwrap_or_default())
+}
+
+/// Total experience for each skill
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockSkills {
+    #[serde(rename = "experience_skill_farming", deserialize_with = "or_default")]
+    pub farming: Option<f64>,
+    #[serde(rename = "experience_skill_mining", deserialize_with = "or_default")]
+    pub mining: Option<f64>,
+    #[serde(rename = "experience_skill_combat", deserialize_with = "or_default")]
+    pub combat: Option<f64>,
+    #[serde(rename = "experience_skill_foraging", deserialize_with = "or_default")]
+    pub foraging: Option<f64>,
+    #[serde(rename = "experience_skill_fishing", deserialize_with = "or_default")]
+    pub fishing: Option<f64>,
+    #[serde(
+        rename = "experience_skill_enchanting",
+        deserialize_with = "or_default"
+    )]
+    pub enchanting: Option<f64>,
+    #[serde(rename = "experience_skill_alchemy", deserialize_with = "or_default")]
+    pub alchemy: Option<f64>,
+    #[serde(rename = "experience_skill_taming", deserialize_with = "or_default")]
+    pub taming: Option<f64>,
+    #[serde(rename = "experience_skill_carpentry", deserialize_with = "or_default")]
+    pub carpentry: Option<f64>,
+    #[serde(
+        rename = "experience_skill_runecrafting",
+        deserialize_with = "or_default"
+    )]
+    pub runecrafting: Option<f64>,
+    #[serde(rename = "experience_skill_social2", deserialize_with = "or_default")]
+    pub social: Option<f64>,
+}
+
+impl SkyblockSkills {
+    pub fn get(&self, skill: Skill) -> Option<f64> {
+        match skill {
+            Skill::Farming => self.farming,
+            Skill::Mining => self.mining,
+            Skill::Combat => self.combat,
+            Skill::Foraging => self.foraging,
+            Skill::Fishing => self.fishing,
+            Skill::Enchanting => self.enchanting,
+            Skill::Alchemy => self.alchemy,
+            Skill::Taming => self.taming,
+            Skill::Carpentry => self.carpentry,
+            Skill::Runecrafting => self.runecrafting,
+            Skill::Social => self.social,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockSlayerBoss {
+    #[serde(deserialize_with = "or_default")]
+    pub xp: f64,
+    #[serde(deserialize_with = "or_default")]
+    pub claimed_levels: HashMap<String, bool>,
+    #[serde(deserialize_with = "or_default")]
+    pub boss_kills_tier_0: i64,
+    #[serde(deserialize_with = "or_default")]
+    pub boss_kills_tier_1: i64,
+    #[serde(deserialize_with = "or_default")]
+    pub boss_kills_tier_2: i64,
+    #[serde(deserialize_with = "or_default")]
+    pub boss_kills_tier_3: i64,
+    #[serde(deserialize_with = "or_default")]
+    pub boss_kills_tier_4: i64,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl SkyblockSlayerBoss {
+    /// Get the kills of each tier, starting from tier 1
+    pub fn get_boss_kills(&self) -> [i64; 5] {
+        [
+            self.boss_kills_tier_0,
+            self.boss_kills_tier_1,
+            self.boss_kills_tier_2,
+            self.boss_kills_tier_3,
+            self.boss_kills_tier_4,
+        ]
+    }
+
+    pub fn get_total_boss_kills(&self) -> i64 {
+        self.get_boss_kills().iter().sum()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockDungeons {
+    #[serde(deserialize_with = "or_default")]
+    pub dungeon_types: HashMap<String, SkyblockDungeonType>,
+    #[serde(deserialize_with = "or_default")]
+    pub player_classes: HashMap<String, SkyblockDungeonClass>,
+    #[serde(deserialize_with = "or_default")]
+    pub selected_dungeon_class: Option<String>,
+    /// Only in the v2 layout
+    #[serde(deserialize_with = "or_default")]
+    pub secrets: Option<f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub dungeon_journal: Option<SkyblockDungeonJournal>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl SkyblockDungeons {
+    pub fn get_catacombs(&self) -> Option<&SkyblockDungeonType> {
+        self.dungeon_types.get("catacombs")
+    }
+
+    pub fn get_master_catacombs(&self) -> Option<&SkyblockDungeonType> {
+        self.dungeon_types.get("master_catacombs")
+    }
+
+    pub fn get_selected_class(&self) -> Option<DungeonClass> {
+        self.selected_dungeon_class
+            .as_deref()
+            .and_then(DungeonClass::from_name)
+    }
+
+    /// Highest Catacombs floor completed, or the highest Master Mode floor with `master`
+    pub fn get_highest_floor_completed(&self, master: bool) -> Option<i64> {
+        if master {
+            self.get_master_catacombs()
+        } else {
+            self.get_catacombs()
+        }
+        .and_then(SkyblockDungeonType::get_highest_floor_completed)
+    }
+
+    /// Master Mode unlocks after completing Catacombs floor 7
+    pub fn is_master_mode_unlocked(&self) -> bool {
+        self.get_highest_floor_completed(false)
+            .is_some_and(|floor| floor >= 7)
+    }
+}
+
+/// Stats of a dungeon. Maps are keyed by floor number, with `0` being the entrance.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockDungeonType {
+    #[serde(deserialize_with = "or_default")]
+    pub experience: f64,
+    #[serde(deserialize_with = "or_default")]
+    pub highest_tier_completed: Option<i64>,
+    #[serde(deserialize_with = "or_default")]
+    pub times_played: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub tier_completions: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub milestone_completions: HashMap<String, f64>,
+    /// Fastest times in milliseconds
+    #[serde(deserialize_with = "or_default")]
+    pub fastest_time: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub fastest_time_s: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub fastest_time_s_plus: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub best_score: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub watcher_kills: HashMap<String, f64>,
+    #[serde(deserialize_with = "or_default")]
+    pub mobs_killed: HashMap<String, f64>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl SkyblockDungeonType {
+    pub fn get_completions(&self, floor: i64) -> i64 {
+        get_floor_stat(&self.tier_completions, floor).unwrap_or(0.0) as i64
+    }
+
+    /// Completions of every floor, ignoring the `total` entry
+    pub fn get_total_completions(&self) -> i64 {
+        self.tier_completions
+            .iter()
+            .filter(|(floor, _)| floor.parse::<i64>().is_ok())
+            .map(|(_, completions)| *completions as i64)
+            .sum()
+    }
+
+    pub fn get_fastest_time(&self, floor: i64) -> Option<i64> {
+        get_floor_stat(&self.fastest_time, floor).map(|time| time as i64)
+    }
+
+    pub fn get_fastest_time_s(&self, floor: i64) -> Option<i64> {
+        get_floor_stat(&self.fastest_time_s, floor).map(|time| time as i64)
+    }
+
+    pub fn get_fastest_time_s_plus(&self, floor: i64) -> Option<i64> {
+        get_floor_stat(&self.fastest_time_s_plus, floor).map(|time| time as i64)
+    }
+
+    pub fn get_best_score(&self, floor: i64) -> Option<i64> {
+        get_floor_stat(&self.best_score, floor).map(|score| score as i64)
+    }
+
+    pub fn get_watcher_kills(&self, floor: i64) -> i64 {
+        get_floor_stat(&self.watcher_kills, floor).unwrap_or(0.0) as i64
+    }
+
+    /// Uses `highest_tier_completed`, falling back to the completions of each floor
+    pub fn get_highest_floor_completed(&self) -> Option<i64> {
+        self.highest_tier_completed.or_else(|| {
+            self.tier_completions
+                .iter()
+                .filter(|(_, completions)| **completions > 0.0)
+                .filter_map(|(floor, _)| floor.parse().ok())
+                .max()
+        })
+    }
+}
+
+fn get_floor_stat(stats: &HashMap<String, f64>, floor: i64) -> Option<f64> {
+    stats.get(&floor.to_string()).copied()
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockDungeonClass {
+    #[serde(deserialize_with = "or_default")]
+    pub experience: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockDungeonJournal {
+    /// Pages found of each journal in the legacy layout
+    #[serde(deserialize_with = "or_default")]
+    pub journal_entries: HashMap<String, Vec<i64>>,
+    /// Completed journals in the v2 layout
+    #[serde(deserialize_with = "or_default")]
+    pub unlocked_journals: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockPet {
+    #[serde(deserialize_with = "or_default")]
+    pub uuid: Option<String>,
+    #[serde(rename = "type", deserialize_with = "or_default")]
+    pub pet_type: String,
+    #[serde(deserialize_with = "or_default")]
+    pub exp: f64,
+    #[serde(deserialize_with = "or_default")]
+    pub active: bool,
+    #[serde(deserialize_with = "or_default")]
+    pub tier: String,
+    #[serde(rename = "heldItem", deserialize_with = "or_default")]
+    pub held_item: Option<String>,
+    #[serde(rename = "candyUsed", deserialize_with = "or_default")]
+    pub candy_used: i64,
+    #[serde(deserialize_with = "or_default")]
+    pub skin: Option<String>,
+}
+
+/// Base64 gzipped NBT for an inventory
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockInventoryData {
+    #[serde(rename = "type", deserialize_with = "or_default")]
+    pub data_type: i64,
+    #[serde(deserialize_with = "or_default")]
+    pub data: String,
+}
+
+impl SkyblockInventoryData {
+    pub fn get_items(&self) -> Option<Vec<Option<SkyblockItem>>> {
+        parse_items(&self.data).ok()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockInventories {
+    #[serde(deserialize_with = "or_default")]
+    pub inv_contents: Option<SkyblockInventoryData>,
+    #[serde(deserialize_with = "or_default")]
+    pub inv_armor: Option<SkyblockInventoryData>,
+    #[serde(deserialize_with = "or_default")]
+    pub equippment_contents: Option<SkyblockInventoryData>,
+    #[serde(deserialize_with = "or_default")]
+    pub ender_chest_contents: Option<SkyblockInventoryData>,
+    #[serde(deserialize_with = "or_default")]
+    pub wardrobe_contents: Option<SkyblockInventoryData>,
+    #[serde(deserialize_with = "or_default")]
+    pub personal_vault_contents: Option<SkyblockInventoryData>,
+    #[serde(deserialize_with = "or_default")]
+    pub talisman_bag: Option<SkyblockInventoryData>,
+    #[serde(deserialize_with = "or_default")]
+    pub backpack_contents: HashMap<String, SkyblockInventoryData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockObjective {
+    #[serde(deserialize_with = "or_default")]
+    pub status: String,
+    #[serde(deserialize_with = "or_default")]
+    pub progress: f64,
+    #[serde(deserialize_with = "or_default")]
+    pub completed_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockMiningCore {
+    #[serde(deserialize_with = "or_default")]
+    pub experience: f64,
+    #[serde(deserialize_with = "or_default")]
+    pub nodes: HashMap<String, Value>,
+    #[serde(deserialize_with = "or_default")]
+    pub powder_mithril: f64,
+    #[serde(deserialize_with = "or_default")]
+    pub powder_spent_mithril: f64,
+    #[serde(deserialize_with = "or_default")]
+    pub powder_gemstone: f64,
+    #[serde(deserialize_with = "or_default")]
+    pub powder_spent_gemstone: f64,
+    #[serde(deserialize_with = "or_default")]
+    pub tokens: i64,
+    #[serde(deserialize_with = "or_default")]
+    pub tokens_spent: i64,
+    #[serde(deserialize_with = "or_default")]
+    pub selected_pickaxe_ability: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockJacob {
+    #[serde(deserialize_with = "or_default")]
+    pub perks: HashMap<String, i64>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct SkyblockLeveling {
+    /// SkyBlock level experience, where each level is 100 experience
+    #[serde(deserialize_with = "or_default")]
+    pub experience: f64,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// Paths of member fields that the v2 profile layout moved into nested objects, as
+/// `(legacy, v2)` pairs
+pub(crate) const V2_MEMBER_PATHS: &[(&str, &str)] = &[
+    ("coin_purse", "currencies.coin_purse"),
+    ("bank_account", "profile.bank_account"),
+    ("first_join", "profile.first_join"),
+    ("fairy_souls_collected", "fairy_soul.total_collected"),
+    (
+        "experience_skill_farming",
+        "player_data.experience.SKILL_FARMING",
+    ),
+    (
+        "experience_skill_mining",
+        "player_data.experience.SKILL_MINING",
+    ),
+    (
+        "experience_skill_combat",
+        "player_data.experience.SKILL_COMBAT",
+    ),
+    (
+        "experience_skill_foraging",
+        "player_data.experience.SKILL_FORAGING",
+    ),
+    (
+        "experience_skill_fishing",
+        "player_data.experience.SKILL_FISHING",
+    ),
+    (
+        "experience_skill_enchanting",
+        "player_data.experience.SKILL_ENCHANTING",
+    ),
+    (
+        "experience_skill_alchemy",
+        "player_data.experience.SKILL_ALCHEMY",
+    ),
+    (
+        "experience_skill_taming",
+        "player_data.experience.SKILL_TAMING",
+    ),
+    (
+        "experience_skill_carpentry",
+        "player_data.experience.SKILL_CARPENTRY",
+    ),
+    (
+        "experience_skill_runecrafting",
+        "player_data.experience.SKILL_RUNECRAFTING",
+    ),
+    (
+        "experience_skill_social2",
+        "player_data.experience.SKILL_SOCIAL",
+    ),
+    ("unlocked_coll_tiers", "player_data.unlocked_coll_tiers"),
+    ("crafted_generators", "player_data.crafted_generators"),
+    ("inv_contents", "inventory.inv_contents"),
+    ("inv_armor", "inventory.inv_armor"),
+    ("equippment_contents", "inventory.equipment_contents"),
+    ("ender_chest_contents", "inventory.ender_chest_contents"),
+    ("wardrobe_contents", "inventory.wardrobe_contents"),
+    (
+        "personal_vault_contents",
+        "inventory.personal_vault_contents",
+    ),
+    ("talisman_bag", "inventory.bag_contents.talisman_bag"),
+    ("backpack_contents", "inventory.backpack_contents"),
+    ("sacks_counts", "inventory.sacks_counts"),
+    ("slayer_bosses", "slayer.slayer_bosses"),
+    ("pets", "pets_data.pets"),
+    ("jacob2", "jacobs_contest"),
+];
+
+/// Get the v2 path of a legacy member path, if the v2 layout moved it
+pub(crate) fn get_v2_path(legacy_path: &str) -> Option<String> {
+    let (key, rest) = match legacy_path.split_once('.') {
+        Some((key, rest)) => (key, Some(rest)),
+        None => (legacy_path, None),
+    };
+
+    V2_MEMBER_PATHS
+        .iter()
+        .find(|(legacy, _)| *legacy == key)
+        .map(|(_, v2)| match rest {
+            Some(rest) => format!("{v2}.{rest}"),
+            None => v2.to_string(),
+        })
+}
+
+/// Convert a member in the v2 layout to the legacy flat layout. Legacy fields are kept when
+/// present, so members already in the legacy layout are returned unchanged.
+pub(crate) fn to_legacy_layout(member: &Value) -> Value {
+    let mut legacy = member.clone();
+    let Some(object) = legacy.as_object_mut() else {
+        return legacy;
+    };
+
+    for (legacy_key, v2_path) in V2_MEMBER_PATHS {
+        if object.contains_key(*legacy_key) {
+            continue;
+        }
+        if let Some(value) = member.get_property(v2_path) {
+            object.insert(legacy_key.to_string(), value.clone());
+        }
+    }
+
+    // v2 nests stats by category (e.g. `kills.zombie`) where the legacy layout joins them with `_`
+    if !object.contains_key("stats") {
+        if let Some(player_stats) = member.get("player_stats") {
+            let mut stats = Map::new();
+            flatten_stats("", player_stats, &mut stats);
+            object.insert("stats".to_string(), Value::Object(stats));
+        }
+    }
+
+    // v2 moves objectives next to the tutorial list
+    if let Some(objectives) = member.get_property("objectives.objectives") {
+        object.insert("objectives".to_string(), objectives.clone());
+    }
+
+    legacy
+}
+
+fn flatten_stats(prefix: &str, value: &Value, stats: &mut Map<String, Value>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                // `kills.total` was `kills`
+                let key = match (prefix, key.as_str()) {
+                    ("", _) => key.clone(),
+                    (_, "total") => prefix.to_string(),
+                    _ => format!("{prefix}_{key}"),
+                };
+                flatten_stats(&key, value, stats);
+            }
+        }
+        Value::Number(_) => {
+            stats.insert(prefix.to_string(), value.clone());
+        }
+        _ => {}
+    }
+}
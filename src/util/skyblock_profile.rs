@@ -1,11 +1,18 @@
-use super::utils::parse_nbt;
+use super::{
+    leveling_tables::LevelingTables,
+    skyblock_item::SkyblockItem,
+    skyblock_member::{get_v2_path, SkyblockDungeons, SkyblockMember, SkyblockSlayerBoss},
+    utils::{parse_items, to_title_case},
+};
 use crate::util::constants::{
-    BLAZE_EXP, CATACOMBS_EXP, CRAFTED_MINIONS_TO_SLOTS, ENDERMAN_EXP, HOTM_EXP, LEVELING_CAPS,
-    LEVELING_EXP, PET_EXP, PET_RARITY_OFFSET, RUNECRAFTING_EXP, SOCIAL_EXP, SPIDER_EXP, WOLF_EXP,
-    ZOMBIE_EXP,
+    CATACOMBS_OVERFLOW_EXP, CRAFTED_MINIONS_TO_SLOTS, PET_SCORE_MAGIC_FIND, PET_STATS,
+    SKILL_OVERFLOW_SLOPE,
 };
 use crate::{
-    types::gamemode::Gamemode,
+    types::{
+        dungeon_class::DungeonClass, gamemode::Gamemode, rarity::Rarity, skill::Skill,
+        slayer::Slayer,
+    },
     util::generic_json::{Property, Raw},
 };
 
@@ -49,33 +56,146 @@ impl Raw for SkyblockProfile {
 }
 
 impl SkyblockProfile {
-    pub fn get_purse_coins(&self, uuid: &str) -> Option<f64> {
-        self.get_float_property(&format!("{uuid}.coin_purse"))
+    /// Get the typed member. Returns `None` if the uuid isn't a member or the data is malformed.
+    pub fn member(&self, uuid: &str) -> Option<SkyblockMember> {
+        self.members.get(uuid).and_then(SkyblockMember::from_raw)
     }
 
-    pub fn get_skill(&self, uuid: &str, skill_name: &str) -> Option<LevelingStruct> {
-        self.get_int_property(&format!(
-            "{}.experience_skill_{}",
-            uuid,
-            if skill_name == "social" {
-                "social2"
-            } else {
-                skill_name
+    pub fn get_member_uuids(&self) -> Vec<&str> {
+        self.members
+            .as_object()
+            .map(|members| members.keys().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Resolve a legacy member path (e.g. `coin_purse`) to the path used by this profile, which
+    /// differs for profiles in the v2 layout
+    fn get_member_path(&self, uuid: &str, path: &str) -> String {
+        let legacy_path = format!("{uuid}.{path}");
+        if self.get_property(&legacy_path).is_none() {
+            if let Some(v2_path) = get_v2_path(path).map(|v2_path| format!("{uuid}.{v2_path}")) {
+                if self.get_property(&v2_path).is_some() {
+                    return v2_path;
+                }
             }
-        ))
-        .map(|skill_exp| self.skill_exp_to_info(uuid, skill_name, skill_exp))
+        }
+        legacy_path
     }
 
-    fn skill_exp_to_info(&self, uuid: &str, skill_name: &str, skill_exp: i64) -> LevelingStruct {
-        let leveling_table = match skill_name {
-            "catacombs" => *CATACOMBS_EXP,
-            "runecrafting" => *RUNECRAFTING_EXP,
-            "social" => *SOCIAL_EXP,
-            "hotm" => *HOTM_EXP,
-            _ => *LEVELING_EXP,
-        };
-        let max_level = self.get_max_level(uuid, skill_name);
+    pub fn get_purse_coins(&self, uuid: &str) -> Option<f64> {
+        self.get_float_property(&self.get_member_path(uuid, "coin_purse"))
+    }
+
+    /// Get the personal bank balance of a coop member
+    pub fn get_bank_account(&self, uuid: &str) -> Option<f64> {
+        self.get_float_property(&self.get_member_path(uuid, "bank_account"))
+    }
+
+    /// Get the SkyBlock level, including progress to the next level
+    pub fn get_skyblock_level(&self, uuid: &str) -> Option<f64> {
+        self.get_float_property(&format!("{uuid}.leveling.experience"))
+            .map(|exp| exp / 100.0)
+    }
 
+    /// Get a skill. With `overflow`, levels past the max level are reported as overflow levels.
+    pub fn get_skill(&self, uuid: &str, skill: Skill, overflow: bool) -> Option<LevelingStruct> {
+        self.get_int_property(&self.get_member_path(uuid, &skill.get_exp_key()))
+            .map(|skill_exp| {
+                Self::skill_exp_to_info(
+                    skill.name(),
+                    skill_exp,
+                    LevelingTables::current().get_skill_table(skill),
+                    self.get_max_level(uuid, skill),
+                    overflow.then_some(if skill.is_cosmetic() {
+                        Overflow::ExpOnly
+                    } else {
+                        Overflow::Skill
+                    }),
+                )
+            })
+    }
+
+    /// Get every skill the member has experience in
+    pub fn get_all_skills(&self, uuid: &str, overflow: bool) -> HashMap<Skill, LevelingStruct> {
+        Skill::ALL
+            .into_iter()
+            .filter_map(|skill| {
+                self.get_skill(uuid, skill, overflow)
+                    .map(|info| (skill, info))
+            })
+            .collect()
+    }
+
+    /// Get the skill average, counting skills without experience as level 0. Returns `None` if
+    /// the member has no skill data, such as when the skills API is disabled.
+    pub fn get_skill_average(&self, uuid: &str, excluded: &[Skill]) -> Option<f64> {
+        self.get_skill_breakdown(uuid, excluded)
+            .map(|breakdown| breakdown.average)
+    }
+
+    /// Get the skill average including progress to the next level of each skill
+    pub fn get_skill_average_with_progress(&self, uuid: &str, excluded: &[Skill]) -> Option<f64> {
+        self.get_skill_breakdown(uuid, excluded)
+            .map(|breakdown| breakdown.average_with_progress)
+    }
+
+    pub fn get_total_skill_exp(&self, uuid: &str, excluded: &[Skill]) -> Option<i64> {
+        self.get_skill_breakdown(uuid, excluded)
+            .map(|breakdown| breakdown.total_exp)
+    }
+
+    /// Get the leveling, capped at the max level, of every skill not in
+    /// `excluded`. Use [`Skill::AVERAGE_EXCLUDED`] for the usual skill average.
+    pub fn get_skill_breakdown(&self, uuid: &str, excluded: &[Skill]) -> Option<SkillBreakdown> {
+        let all_skills = self.get_all_skills(uuid, false);
+        if all_skills.is_empty() {
+            return None;
+        }
+
+        let tables = LevelingTables::current();
+        let skills = Skill::ALL
+            .into_iter()
+            .filter(|skill| !excluded.contains(skill))
+            .map(|skill| {
+                let leveling = all_skills.get(&skill).cloned().unwrap_or_else(|| {
+                    Self::skill_exp_to_info(
+                        skill.name(),
+                        0,
+                        tables.get_skill_table(skill),
+                        self.get_max_level(uuid, skill),
+                        None,
+                    )
+                });
+
+                SkillBreakdownEntry { skill, leveling }
+            })
+            .collect::<Vec<_>>();
+
+        let count = skills.len().max(1) as f64;
+        Some(SkillBreakdown {
+            average: skills
+                .iter()
+                .map(|entry| entry.leveling.level as f64)
+                .sum::<f64>()
+                / count,
+            average_with_progress: skills
+                .iter()
+                .map(|entry| entry.leveling.get_progress_level())
+                .sum::<f64>()
+                / count,
+            total_exp: skills.iter().map(|entry| entry.leveling.total_exp).sum(),
+            farming_cap_upgrade: self.get_farming_cap_upgrade(uuid),
+            skills,
+        })
+    }
+
+    pub(crate) fn skill_exp_to_info(
+        skill_name: &str,
+        skill_exp: i64,
+        leveling_table: &[i64],
+        max_level: i64,
+        overflow: Option<Overflow>,
+    ) -> LevelingStruct {
         if skill_exp == 0 {
             return LevelingStruct {
                 name: skill_name.to_string(),
@@ -85,13 +205,17 @@ impl SkyblockProfile {
                 current_exp: 0,
                 exp_for_next: 0,
                 progress_to_next: 0.0,
+                overflow_level: 0,
+                overflow_exp: 0,
             };
         }
 
         let mut exp_total = 0;
         let mut level = 0;
         for i in 0..max_level {
-            let cur_exp_needed = leveling_table[i as usize];
+            let Some(&cur_exp_needed) = leveling_table.get(i as usize) else {
+                break;
+            };
             exp_total += cur_exp_needed;
 
             if exp_total > skill_exp {
@@ -102,13 +226,28 @@ impl SkyblockProfile {
             level = i + 1;
         }
 
-        let exp_current = skill_exp - exp_total;
-        let exp_for_next = if level < max_level {
-            leveling_table[level as usize]
+        let mut exp_current = skill_exp - exp_total;
+        let mut exp_for_next = if level < max_level {
+            leveling_table.get(level as usize).copied().unwrap_or(0)
         } else {
             0
         };
 
+        let mut overflow_level = 0;
+        let mut overflow_exp = 0;
+        if let Some(overflow) = overflow.filter(|_| level == max_level) {
+            overflow_exp = exp_current;
+            loop {
+                exp_for_next =
+                    overflow.get_exp_for_level(leveling_table, max_level, level + overflow_level);
+                if exp_for_next == 0 || exp_current < exp_for_next {
+                    break;
+                }
+                exp_current -= exp_for_next;
+                overflow_level += 1;
+            }
+        }
+
         let progress = if exp_for_next > 0 {
             (exp_current as f64 / exp_for_next as f64).clamp(0.0, 1.0)
         } else {
@@ -123,12 +262,15 @@ impl SkyblockProfile {
             current_exp: exp_current,
             exp_for_next,
             progress_to_next: progress,
+            overflow_level,
+            overflow_exp,
         }
     }
 
-    pub fn get_max_level(&self, uuid: &str, name: &str) -> i64 {
-        LEVELING_CAPS.get(name).unwrap_or(&50)
-            + if name == "farming" {
+    /// Get the max level of a skill, including the farming level cap upgrade
+    pub fn get_max_level(&self, uuid: &str, skill: Skill) -> i64 {
+        LevelingTables::current().get_skill_max_level(skill)
+            + if skill == Skill::Farming {
                 self.get_farming_cap_upgrade(uuid)
             } else {
                 0
@@ -136,24 +278,29 @@ impl SkyblockProfile {
     }
 
     pub fn get_farming_cap_upgrade(&self, uuid: &str) -> i64 {
-        self.get_int_property(&format!("{uuid}.jacob2.perks.farming_level_cap"))
+        self.get_int_property(&self.get_member_path(uuid, "jacob2.perks.farming_level_cap"))
             .unwrap_or(0)
     }
 
     pub fn get_hotm(&self, uuid: &str) -> Option<LevelingStruct> {
         self.get_int_property(&format!("{uuid}.mining_core.experience"))
-            .map(|exp| self.skill_exp_to_info(uuid, "hotm", exp))
+            .map(|exp| {
+                let tables = LevelingTables::current();
+                Self::skill_exp_to_info(
+                    "hotm",
+                    exp,
+                    &tables.hotm,
+                    tables.get_hotm_max_level(),
+                    None,
+                )
+            })
     }
 
-    fn slayer_exp_to_info(&self, uuid: &str, slayer_name: &str, slayer_exp: i64) -> LevelingStruct {
-        let leveling_table = match slayer_name {
-            "zombie" => *ZOMBIE_EXP,
-            "wolf" => *WOLF_EXP,
-            "spider" => *SPIDER_EXP,
-            "enderman" => *ENDERMAN_EXP,
-            _ => *BLAZE_EXP,
-        };
-        let max_level = self.get_max_level(uuid, slayer_name);
+    fn slayer_exp_to_info(slayer: Slayer, slayer_exp: i64, overflow: bool) -> LevelingStruct {
+        let slayer_name = slayer.name();
+        let tables = LevelingTables::current();
+        let leveling_table = tables.get_slayer_table(slayer);
+        let max_level = tables.get_slayer_max_level(slayer);
 
         if slayer_exp == 0 {
             return LevelingStruct {
@@ -164,6 +311,8 @@ impl SkyblockProfile {
                 current_exp: 0,
                 exp_for_next: 0,
                 progress_to_next: 0.0,
+                overflow_level: 0,
+                overflow_exp: 0,
             };
         }
 
@@ -187,6 +336,12 @@ impl SkyblockProfile {
         } else {
             0
         };
+        let overflow_level = 0;
+        let overflow_exp = if overflow && level == max_level {
+            exp_current
+        } else {
+            0
+        };
 
         let progress = if exp_for_next > 0 {
             (exp_current as f64 / exp_for_next as f64).clamp(0.0, 1.0)
@@ -202,94 +357,205 @@ impl SkyblockProfile {
             current_exp: exp_current,
             exp_for_next,
             progress_to_next: progress,
+            overflow_level,
+            overflow_exp,
         }
     }
 
-    pub fn get_slayer(&self, uuid: &str, slayer_name: &str) -> Option<LevelingStruct> {
-        self.get_int_property(&format!("{uuid}.slayer_bosses.{slayer_name}.xp"))
-            .map(|exp| self.slayer_exp_to_info(uuid, slayer_name, exp))
+    /// Get a slayer. With `overflow`, exp past the max level is reported as overflow exp. Slayers
+    /// have no levels past the max level, so `overflow_level` is always 0, the same as for
+    /// cosmetic skills.
+    pub fn get_slayer(&self, uuid: &str, slayer: Slayer, overflow: bool) -> Option<LevelingStruct> {
+        self.get_int_property(
+            &self.get_member_path(uuid, &format!("slayer_bosses.{}.xp", slayer.name())),
+        )
+        .map(|exp| Self::slayer_exp_to_info(slayer, exp, overflow))
+    }
+
+    /// Get every slayer the member has experience in
+    pub fn get_all_slayers(&self, uuid: &str, overflow: bool) -> HashMap<Slayer, LevelingStruct> {
+        Slayer::ALL
+            .into_iter()
+            .filter_map(|slayer| {
+                self.get_slayer(uuid, slayer, overflow)
+                    .map(|info| (slayer, info))
+            })
+            .collect()
+    }
+
+    /// Get the kills of each tier of a slayer, starting from tier 1
+    pub fn get_slayer_boss_kills(&self, uuid: &str, slayer: Slayer) -> Option<[i64; 5]> {
+        self.member(uuid)?
+            .slayer_bosses
+            .get(slayer.name())
+            .map(SkyblockSlayerBoss::get_boss_kills)
+    }
+
+    /// Get the stats of every slayer the member has started. Coins spent is estimated from boss
+    /// kills and the spawn cost of each tier, so failed quests aren't included.
+    pub fn get_slayer_summary(&self, uuid: &str) -> Option<SlayerSummary> {
+        let member = self.member(uuid)?;
+
+        let slayers: HashMap<Slayer, SlayerStats> = Slayer::ALL
+            .into_iter()
+            .filter_map(|slayer| {
+                let boss = member.slayer_bosses.get(slayer.name())?;
+                let boss_kills = boss.get_boss_kills();
+                Some((
+                    slayer,
+                    SlayerStats {
+                        leveling: Self::slayer_exp_to_info(slayer, boss.xp as i64, false),
+                        boss_kills,
+                        total_boss_kills: boss.get_total_boss_kills(),
+                        coins_spent: slayer
+                            .get_spawn_costs()
+                            .iter()
+                            .zip(boss_kills)
+                            .map(|(cost, kills)| cost * kills)
+                            .sum(),
+                    },
+                ))
+            })
+            .collect();
+
+        Some(SlayerSummary {
+            total_exp: slayers.values().map(|stats| stats.leveling.total_exp).sum(),
+            total_boss_kills: slayers.values().map(|stats| stats.total_boss_kills).sum(),
+            coins_spent: slayers.values().map(|stats| stats.coins_spent).sum(),
+            slayers,
+        })
     }
 
-    pub fn get_catacombs(&self, uuid: &str) -> Option<LevelingStruct> {
+    /// Get catacombs. With `overflow`, levels past 50 are reported as overflow levels.
+    pub fn get_catacombs(&self, uuid: &str, overflow: bool) -> Option<LevelingStruct> {
         self.get_int_property(&format!(
             "{uuid}.dungeons.dungeon_types.catacombs.experience"
         ))
-        .map(|exp| self.skill_exp_to_info(uuid, "catacombs", exp))
+        .map(|exp| {
+            let tables = LevelingTables::current();
+            Self::skill_exp_to_info(
+                "catacombs",
+                exp,
+                &tables.catacombs,
+                tables.get_catacombs_max_level(),
+                overflow.then_some(Overflow::Catacombs),
+            )
+        })
     }
 
-    pub fn get_dungeon_class(&self, uuid: &str, class_name: &str) -> Option<LevelingStruct> {
+    pub fn get_dungeon_class(
+        &self,
+        uuid: &str,
+        class: DungeonClass,
+        overflow: bool,
+    ) -> Option<LevelingStruct> {
         self.get_int_property(&format!(
-            "{uuid}.dungeons.player_classes.{class_name}.experience"
+            "{uuid}.dungeons.player_classes.{}.experience",
+            class.name()
         ))
-        .map(|exp| self.skill_exp_to_info(uuid, "catacombs", exp))
+        .map(|exp| {
+            let tables = LevelingTables::current();
+            Self::skill_exp_to_info(
+                class.name(),
+                exp,
+                &tables.catacombs,
+                tables.get_catacombs_max_level(),
+                overflow.then_some(Overflow::Catacombs),
+            )
+        })
     }
 
-    pub fn get_inventory(&self, uuid: &str) -> Option<Value> {
-        if let Some(data) = self.get_str_property(&format!("{uuid}.inv_contents.data")) {
-            parse_nbt(data)
-        } else {
-            None
-        }
+    /// Get every dungeon class the member has experience in
+    pub fn get_all_classes(
+        &self,
+        uuid: &str,
+        overflow: bool,
+    ) -> HashMap<DungeonClass, LevelingStruct> {
+        DungeonClass::ALL
+            .into_iter()
+            .filter_map(|class| {
+                self.get_dungeon_class(uuid, class, overflow)
+                    .map(|info| (class, info))
+            })
+            .collect()
     }
 
-    pub fn get_personal_vault(&self, uuid: &str) -> Option<Value> {
-        if let Some(data) = self.get_str_property(&format!("{uuid}.personal_vault_contents.data")) {
-            parse_nbt(data)
-        } else {
-            None
-        }
+    /// Get the average level of the five dungeon classes, counting missing classes as 0
+    pub fn get_class_average(&self, uuid: &str, overflow: bool) -> f64 {
+        let classes = self.get_all_classes(uuid, overflow);
+        classes
+            .values()
+            .map(|class| (class.level + class.overflow_level) as f64)
+            .sum::<f64>()
+            / DungeonClass::ALL.len() as f64
     }
 
-    pub fn get_talisman_bag(&self, uuid: &str) -> Option<Value> {
-        if let Some(data) = self.get_str_property(&format!("{uuid}.talisman_bag.data")) {
-            parse_nbt(data)
-        } else {
-            None
-        }
+    pub fn get_dungeons(&self, uuid: &str) -> Option<SkyblockDungeons> {
+        self.member(uuid)?.dungeons
     }
 
-    pub fn get_equippment(&self, uuid: &str) -> Option<Value> {
-        if let Some(data) = self.get_str_property(&format!("{uuid}.equippment_contents.data")) {
-            parse_nbt(data)
-        } else {
-            None
-        }
+    pub fn get_dungeon_secrets(&self, uuid: &str) -> Option<i64> {
+        self.get_dungeons(uuid)?
+            .secrets
+            .map(|secrets| secrets as i64)
     }
 
-    pub fn get_armor(&self, uuid: &str) -> Option<Value> {
-        if let Some(data) = self.get_str_property(&format!("{uuid}.inv_armor.data")) {
-            parse_nbt(data)
-        } else {
-            None
-        }
+    pub fn get_highest_floor_completed(&self, uuid: &str, master: bool) -> Option<i64> {
+        self.get_dungeons(uuid)?.get_highest_floor_completed(master)
     }
 
-    pub fn get_wardrobe(&self, uuid: &str) -> Option<Value> {
-        if let Some(data) = self.get_str_property(&format!("{uuid}.wardrobe_contents.data")) {
-            parse_nbt(data)
-        } else {
-            None
-        }
+    pub fn is_master_mode_unlocked(&self, uuid: &str) -> bool {
+        self.get_dungeons(uuid)
+            .is_some_and(|dungeons| dungeons.is_master_mode_unlocked())
     }
 
-    pub fn get_ender_chest(&self, uuid: &str) -> Option<Value> {
-        if let Some(data) = self.get_str_property(&format!("{uuid}.ender_chest_contents.data")) {
-            parse_nbt(data)
-        } else {
-            None
-        }
+    pub fn get_inventory(&self, uuid: &str) -> Option<Vec<Option<SkyblockItem>>> {
+        self.get_str_property(&self.get_member_path(uuid, "inv_contents.data"))
+            .and_then(|data| parse_items(data).ok())
+    }
+
+    pub fn get_personal_vault(&self, uuid: &str) -> Option<Vec<Option<SkyblockItem>>> {
+        self.get_str_property(&self.get_member_path(uuid, "personal_vault_contents.data"))
+            .and_then(|data| parse_items(data).ok())
+    }
+
+    pub fn get_talisman_bag(&self, uuid: &str) -> Option<Vec<Option<SkyblockItem>>> {
+        self.get_str_property(&self.get_member_path(uuid, "talisman_bag.data"))
+            .and_then(|data| parse_items(data).ok())
+    }
+
+    pub fn get_equippment(&self, uuid: &str) -> Option<Vec<Option<SkyblockItem>>> {
+        self.get_str_property(&self.get_member_path(uuid, "equippment_contents.data"))
+            .and_then(|data| parse_items(data).ok())
     }
 
-    pub fn get_storage(&self, uuid: &str) -> Option<HashMap<&str, Value>> {
-        if let Some(data) = self.get_object_property(&format!("{uuid}.backpack_contents")) {
+    pub fn get_armor(&self, uuid: &str) -> Option<Vec<Option<SkyblockItem>>> {
+        self.get_str_property(&self.get_member_path(uuid, "inv_armor.data"))
+            .and_then(|data| parse_items(data).ok())
+    }
+
+    pub fn get_wardrobe(&self, uuid: &str) -> Option<Vec<Option<SkyblockItem>>> {
+        self.get_str_property(&self.get_member_path(uuid, "wardrobe_contents.data"))
+            .and_then(|data| parse_items(data).ok())
+    }
+
+    pub fn get_ender_chest(&self, uuid: &str) -> Option<Vec<Option<SkyblockItem>>> {
+        self.get_str_property(&self.get_member_path(uuid, "ender_chest_contents.data"))
+            .and_then(|data| parse_items(data).ok())
+    }
+
+    pub fn get_storage(&self, uuid: &str) -> Option<HashMap<&str, Vec<Option<SkyblockItem>>>> {
+        if let Some(data) =
+            self.get_object_property(&self.get_member_path(uuid, "backpack_contents"))
+        {
             let mut storage = HashMap::new();
             for ele in data {
-                if let Some(bp) = parse_nbt(
-                    ele.1
-                        .get("data")
-                        .and_then(serde_json::Value::as_str)
-                        .unwrap(),
-                ) {
+                if let Some(bp) = ele
+                    .1
+                    .get("data")
+                    .and_then(serde_json::Value::as_str)
+                    .and_then(|data| parse_items(data).ok())
+                {
                     storage.insert(&**ele.0, bp);
                 }
             }
@@ -300,7 +566,7 @@ impl SkyblockProfile {
     }
 
     pub fn get_sacks(&self, uuid: &str) -> Option<HashMap<&str, i64>> {
-        if let Some(data) = self.get_object_property(&format!("{uuid}.sacks_counts")) {
+        if let Some(data) = self.get_object_property(&self.get_member_path(uuid, "sacks_counts")) {
             let mut sacks = HashMap::new();
             for ele in data {
                 sacks.insert(&**ele.0, ele.1.as_i64().unwrap_or(0));
@@ -311,69 +577,54 @@ impl SkyblockProfile {
         }
     }
 
+    /// Get the pets of a member. Pets without a type or tier are skipped.
     pub fn get_pets(&self, uuid: &str) -> Option<Vec<PetStruct>> {
-        if let Some(pets) = self.get_array_property(&format!("{uuid}.pets")) {
-            let mut parsed_pets = Vec::new();
-            for pet in pets {
-                let rarity = pet.get_string_property("tier").unwrap();
-                parsed_pets.push(PetStruct {
-                    leveling: Self::pet_exp_to_info(
-                        pet.get_str_property("type").unwrap(),
-                        pet.get_int_property("exp").unwrap(),
-                        rarity.as_str(),
-                    ),
-                    rarity,
-                    skin: pet.get_string_property("skin"),
-                    held_item: pet.get_string_property("heldItem"),
-                });
-            }
-            Some(parsed_pets)
-        } else {
-            None
-        }
+        self.get_array_property(&self.get_member_path(uuid, "pets"))
+            .map(|pets| pets.iter().filter_map(PetStruct::from_raw).collect())
     }
 
-    fn pet_exp_to_info(pet_name: &str, pet_exp: i64, pet_rarity: &str) -> LevelingStruct {
-        let leveling_table = *PET_EXP;
-        let max_level = 100; // TODO: golden dragon
+    pub fn get_active_pet(&self, uuid: &str) -> Option<PetStruct> {
+        self.get_pets(uuid)?.into_iter().find(|pet| pet.active)
+    }
 
-        if pet_exp == 0 {
-            return LevelingStruct {
-                name: pet_name.to_string(),
-                level: 0,
-                max_level,
-                total_exp: 0,
-                current_exp: 0,
-                exp_for_next: 0,
-                progress_to_next: 0.0,
-            };
+    /// Get the rarity of a pet after its held item. A tier boost raises it by one, up to mythic.
+    pub fn get_effective_pet_rarity(rarity: Rarity, held_item: Option<&str>) -> Rarity {
+        if held_item == Some("PET_ITEM_TIER_BOOST") && rarity < Rarity::Mythic {
+            rarity.get_upgraded()
+        } else {
+            rarity
         }
+    }
 
-        let offset = *PET_RARITY_OFFSET.get(pet_rarity).unwrap();
+    fn pet_exp_to_info(pet_name: &str, pet_exp: i64, pet_rarity: Rarity) -> LevelingStruct {
+        let tables = LevelingTables::current();
+        let leveling_table = &tables.pets;
+        let max_level = tables.get_pet_max_level(pet_name);
+        let offset = tables.get_pet_rarity_offset(pet_rarity);
+        // Levels past the end of the table need the last amount
+        let exp_for_level = |level: i64| {
+            leveling_table
+                .get((offset + level - 1) as usize)
+                .or(leveling_table.last())
+                .copied()
+                .unwrap_or(0)
+        };
 
         let mut exp_total = 0;
         let mut level = 1;
-        let mut is_maxed = true;
-        for i in offset..(leveling_table.len() as i64) {
-            let cur_exp_needed = leveling_table[i as usize];
-            exp_total += cur_exp_needed;
-
-            if exp_total > pet_exp {
-                exp_total -= cur_exp_needed;
-                is_maxed = false;
+        while level < max_level {
+            let cur_exp_needed = exp_for_level(level);
+            if exp_total + cur_exp_needed > pet_exp {
                 break;
             }
 
+            exp_total += cur_exp_needed;
             level += 1;
         }
 
-        if is_maxed {
-            level = 100;
-        }
-
         let exp_current = pet_exp - exp_total;
         let exp_for_next = if level < max_level {
-            leveling_table[(level + offset - 1) as usize]
+            exp_for_level(level)
         } else {
             0
         };
@@ -392,11 +643,13 @@ impl SkyblockProfile {
             current_exp: exp_current,
             exp_for_next,
             progress_to_next: progress,
+            overflow_level: 0,
+            overflow_exp: 0,
         }
     }
 
     pub fn get_fairy_souls(&self, uuid: &str) -> i64 {
-        self.get_int_property(&format!("{uuid}.fairy_souls_collected"))
+        self.get_int_property(&self.get_member_path(uuid, "fairy_souls_collected"))
             .unwrap_or(0)
     }
 
@@ -404,7 +657,10 @@ impl SkyblockProfile {
         let mut unique_minions = HashSet::new();
 
         for member in self.members.as_object().unwrap().values() {
-            if let Some(minions_unwrap) = member.get_array_property("crafted_generators") {
+            if let Some(minions_unwrap) = member
+                .get_array_property("crafted_generators")
+                .or_else(|| member.get_array_property("player_data.crafted_generators"))
+            {
                 for ele in minions_unwrap {
                     unique_minions.insert(ele.as_str().unwrap());
                 }
@@ -423,34 +679,146 @@ impl SkyblockProfile {
         (max as i64) + 5
     }
 
+    /// Get the pet score. Each pet type gives points for its highest rarity (1 for common up to
+    /// 6 for mythic) and 1 more if one of them is max level. Tier boosts don't count.
     pub fn get_pet_score(&self, uuid: &str) -> i64 {
-        let mut pets_map = HashMap::new();
-
-        if let Some(pets) = self.get_pets(uuid) {
-            for ele in pets {
-                let rarity = match ele.rarity.to_lowercase().as_str() {
-                    "common" => 1,
-                    "uncommon" => 2,
-                    "rare" => 3,
-                    "epic" => 4,
-                    "legendary" => 5,
-                    _ => 0,
-                };
-
-                if let Some(cur_rarity) = pets_map.get(&ele.name) {
-                    if cur_rarity < &rarity {
-                        pets_map.insert(ele.name.clone(), rarity);
-                    }
-                } else {
-                    pets_map.insert(ele.name.clone(), rarity);
+        self.get_pets(uuid)
+            .map(|pets| Self::get_pet_score_of(&pets))
+            .unwrap_or(0)
+    }
+
+    fn get_pet_score_of(pets: &[PetStruct]) -> i64 {
+        let mut pets_map: HashMap<&str, (i64, bool)> = HashMap::new();
+
+        for pet in pets {
+            let rarity_score = match pet.rarity {
+                Rarity::Common => 1,
+                Rarity::Uncommon => 2,
+                Rarity::Rare => 3,
+                Rarity::Epic => 4,
+                Rarity::Legendary => 5,
+                Rarity::Mythic => 6,
+                _ => 0,
+            };
+
+            let entry = pets_map.entry(pet.name.as_str()).or_insert((0, false));
+            entry.0 = entry.0.max(rarity_score);
+            entry.1 |= pet.is_maxed();
+        }
+
+        pets_map
+            .values()
+            .map(|(rarity_score, is_maxed)| rarity_score + i64::from(*is_maxed))
+            .sum()
+    }
+
+    /// Get the magic find given by the pet score
+    pub fn get_pet_score_magic_find(&self, uuid: &str) -> i64 {
+        let pet_score = self.get_pet_score(uuid);
+        PET_SCORE_MAGIC_FIND
+            .iter()
+            .take_while(|threshold| pet_score >= **threshold)
+            .count() as i64
+    }
+
+    /// Get the pet types missing at each rarity from the pet rarities of the current
+    /// [`LevelingTables`]. A pet counts as missing at a rarity if it can be that rarity and the
+    /// member has no pet of it at that rarity or higher. The embedded pet rarities are incomplete,
+    /// see [`LevelingTables::with_pets_resource`] to replace them.
+    pub fn get_missing_pets(&self, uuid: &str) -> HashMap<Rarity, Vec<String>> {
+        self.get_missing_pets_with(uuid, &LevelingTables::current().pet_rarities)
+    }
+
+    pub fn get_missing_pets_with(
+        &self,
+        uuid: &str,
+        pet_rarities: &HashMap<String, (Rarity, Rarity)>,
+    ) -> HashMap<Rarity, Vec<String>> {
+        let mut best_rarities = HashMap::new();
+        for pet in self.get_pets(uuid).unwrap_or_default() {
+            let rarity = pet.rarity;
+            let best = best_rarities.entry(pet.name.clone()).or_insert(rarity);
+            *best = (*best).max(rarity);
+        }
+
+        let mut missing: HashMap<Rarity, Vec<String>> = HashMap::new();
+        for (pet_type, (min_rarity, max_rarity)) in pet_rarities {
+            let best = best_rarities.get(pet_type);
+            let mut rarity = *min_rarity;
+            loop {
+                if best.is_none_or(|best| *best < rarity) {
+                    missing.entry(rarity).or_default().push(pet_type.clone());
                 }
+                if rarity >= *max_rarity || rarity.get_upgraded() == rarity {
+                    break;
+                }
+                rarity = rarity.get_upgraded();
             }
         }
 
-        pets_map.values().sum()
+        for pets in missing.values_mut() {
+            pets.sort_unstable();
+        }
+        missing
+    }
+
+    pub fn get_pet_summary(&self, uuid: &str) -> Option<PetSummary> {
+        let pets = self.get_pets(uuid)?;
+
+        let mut by_rarity = HashMap::new();
+        for pet in &pets {
+            *by_rarity.entry(pet.rarity).or_insert(0) += 1;
+        }
+
+        Some(PetSummary {
+            total: pets.len(),
+            unique: pets
+                .iter()
+                .map(|pet| pet.name.as_str())
+                .collect::<HashSet<_>>()
+                .len(),
+            by_rarity,
+            max_level: pets.iter().filter(|pet| pet.is_maxed()).count(),
+            average_level: if pets.is_empty() {
+                0.0
+            } else {
+                pets.iter().map(|pet| pet.level as f64).sum::<f64>() / pets.len() as f64
+            },
+            pet_score: Self::get_pet_score_of(&pets),
+        })
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct SlayerStats {
+    pub leveling: LevelingStruct,
+    /// Kills of each tier, starting from tier 1
+    pub boss_kills: [i64; 5],
+    pub total_boss_kills: i64,
+    pub coins_spent: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SlayerSummary {
+    pub slayers: HashMap<Slayer, SlayerStats>,
+    pub total_exp: i64,
+    pub total_boss_kills: i64,
+    /// Estimated from boss kills
+    pub coins_spent: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct PetSummary {
+    pub total: usize,
+    /// Number of pet types
+    pub unique: usize,
+    pub by_rarity: HashMap<Rarity, usize>,
+    /// Number of pets at their max level
+    pub max_level: usize,
+    pub average_level: f64,
+    pub pet_score: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SkyblockCommunityUpgrades {
     pub currently_upgrading: Option<SkyblockCurrentCommunityUpgrade>,
@@ -490,7 +858,7 @@ pub struct SkyblockTransaction {
     pub initiator_name: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LevelingStruct {
     pub name: String,
     pub level: i64,
@@ -502,6 +870,10 @@ pub struct LevelingStruct {
     pub exp_for_next: i64,
     /// Progress to next level (0.0 to 1.0)
     pub progress_to_next: f64,
+    /// Levels past the max level. Only calculated when overflow is requested.
+    pub overflow_level: i64,
+    /// Exp past what is needed for the max level. Only calculated when overflow is requested.
+    pub overflow_exp: i64,
 }
 
 impl LevelingStruct {
@@ -510,16 +882,158 @@ impl LevelingStruct {
     }
 
     pub fn get_progress_level(&self) -> f64 {
-        (self.current_exp as f64) + self.progress_to_next
+        ((self.level + self.overflow_level) as f64) + self.progress_to_next
+    }
+}
+
+/// How levels past the max level are calculated, following the extensions used by community
+/// tools
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Overflow {
+    /// Each level needs [`SKILL_OVERFLOW_SLOPE`] more exp than the previous one, and the slope
+    /// doubles every 10 levels
+    Skill,
+    /// Each level needs [`CATACOMBS_OVERFLOW_EXP`]
+    Catacombs,
+    /// Only the exp past the max level is reported and the overflow level stays 0. Cosmetic
+    /// skills use this, and slayers report overflow the same way.
+    ExpOnly,
+}
+
+impl Overflow {
+    /// Get the exp needed to go from `level`, which is at least the max level, to the next level,
+    /// or 0 if there are no more levels. Table entries past the max level are never used, since
+    /// skills capped below the table length do not have those levels.
+    fn get_exp_for_level(self, leveling_table: &[i64], max_level: i64, level: i64) -> i64 {
+        match self {
+            Overflow::ExpOnly => 0,
+            Overflow::Skill => {
+                let mut exp = leveling_table
+                    .get(..max_level.clamp(0, leveling_table.len() as i64) as usize)
+                    .and_then(<[i64]>::last)
+                    .copied()
+                    .unwrap_or(0);
+                let mut slope = SKILL_OVERFLOW_SLOPE;
+                for i in 0..=(level - max_level) {
+                    exp += slope;
+                    if (i + 1) % 10 == 0 {
+                        slope *= 2;
+                    }
+                }
+                exp
+            }
+            Overflow::Catacombs => CATACOMBS_OVERFLOW_EXP,
+        }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+pub struct SkillBreakdown {
+    /// Every skill that isn't excluded, including ones without experience
+    pub skills: Vec<SkillBreakdownEntry>,
+    pub average: f64,
+    pub average_with_progress: f64,
+    pub total_exp: i64,
+    /// Farming levels unlocked past 50 from Jacob's perks
+    pub farming_cap_upgrade: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SkillBreakdownEntry {
+    pub skill: Skill,
+    /// Leveling capped at the max level
+    pub leveling: LevelingStruct,
+}
+
+#[derive(Debug, Clone)]
 pub struct PetStruct {
     pub leveling: LevelingStruct,
-    pub rarity: String,
+    pub uuid: Option<String>,
+    pub rarity: Rarity,
+    /// Rarity after the held item, which the leveling uses
+    pub effective_rarity: Rarity,
+    /// Name of the pet type (e.g. `Golden Dragon`)
+    pub display_name: String,
+    pub active: bool,
+    pub candy_used: i64,
     pub skin: Option<String>,
     pub held_item: Option<String>,
+    /// Name of the held item (e.g. `Tier Boost`)
+    pub held_item_name: Option<String>,
+    /// Stats given at the current level and effective rarity. Empty for pets without known
+    /// stats.
+    pub stats: HashMap<String, f64>,
+}
+
+impl PetStruct {
+    /// Parse a pet from the pets array or the `petInfo` of a pet item. Returns `None` if the type
+    /// or tier is missing.
+    pub fn from_raw(pet: &Value) -> Option<PetStruct> {
+        let pet_type = pet.get_str_property("type")?;
+        let rarity = Rarity::from(pet.get_str_property("tier")?);
+        let held_item = pet.get_string_property("heldItem");
+        let effective_rarity =
+            SkyblockProfile::get_effective_pet_rarity(rarity, held_item.as_deref());
+        let leveling = SkyblockProfile::pet_exp_to_info(
+            pet_type,
+            pet.get_int_property("exp").unwrap_or(0),
+            effective_rarity,
+        );
+
+        Some(PetStruct {
+            stats: Self::get_stats(pet_type, effective_rarity, leveling.level),
+            leveling,
+            uuid: pet.get_string_property("uuid"),
+            rarity,
+            effective_rarity,
+            display_name: to_title_case(pet_type),
+            active: pet
+                .get_property("active")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            candy_used: pet.get_int_property("candyUsed").unwrap_or(0),
+            skin: pet.get_string_property("skin"),
+            held_item_name: held_item.as_deref().map(Self::get_held_item_name),
+            held_item,
+        })
+    }
+
+    /// Get the stats of a pet type at a rarity and level. Empty for pets without known stats.
+    pub fn get_stats(pet_type: &str, rarity: Rarity, level: i64) -> HashMap<String, f64> {
+        PET_STATS
+            .get(pet_type)
+            .filter(|_| rarity != Rarity::Unknown)
+            .and_then(|rarities| rarities.iter().rev().find(|(min, _)| *min <= rarity))
+            .map(|(_, stats)| {
+                stats
+                    .iter()
+                    .map(|(stat, base, per_level)| {
+                        (stat.to_string(), base + per_level * level as f64)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_held_item_name(held_item: &str) -> String {
+        let name = held_item.strip_prefix("PET_ITEM_").unwrap_or(held_item);
+        // Leveled items end with their rarity (e.g. `PET_ITEM_COMBAT_SKILL_BOOST_EPIC`)
+        let name = name
+            .rsplit_once('_')
+            .filter(|(_, suffix)| Rarity::from(*suffix) != Rarity::Unknown)
+            .map_or(name, |(name, _)| name);
+        to_title_case(name)
+    }
+
+    /// Get the name as shown in game, such as `§7[Lvl 100] §6Golden Dragon`
+    pub fn get_formatted_name(&self) -> String {
+        format!(
+            "§7[Lvl {}] {}{}",
+            self.level,
+            self.effective_rarity.get_color().get_formatting_code(),
+            self.display_name
+        )
+    }
 }
 
 impl std::ops::Deref for PetStruct {
@@ -1,3 +1,9 @@
+use super::{
+    error::Error,
+    formatting,
+    nbt_tag::{NbtCompound, NbtRoot, NbtTag},
+    skyblock_item::SkyblockItem,
+};
 use base64::{engine::general_purpose, Engine};
 use serde_json::Value;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -41,15 +47,102 @@ pub fn get_total_exp_to_level(level: f64) -> f64 {
     }
 }
 
+/// Get the `i` list Hypixel wraps items in
+fn get_item_list(root: &NbtRoot) -> Result<&NbtTag, Error> {
+    root.get("i")
+        .ok_or_else(|| Error::Nbt("Missing the i list of items".to_string()))
+}
+
+fn to_item_list_root(items: Vec<NbtTag>) -> NbtRoot {
+    let mut compound = NbtCompound::new();
+    compound.insert("i", NbtTag::List(items));
+    NbtRoot {
+        name: String::new(),
+        compound,
+    }
+}
+
+/// Parse base64 gzipped NBT into JSON, or `None` if it can't be parsed
+#[deprecated(note = "use `try_parse_nbt`, which reports why parsing failed")]
 pub fn parse_nbt(data: &str) -> Option<Value> {
-    general_purpose::STANDARD
-        .decode(data)
-        .ok()
-        .and_then(|bytes| {
-            nbt::from_gzip_reader::<_, nbt::Blob>(std::io::Cursor::new(bytes))
-                .ok()
-                .and_then(|nbt| nbt.get("i").and_then(|v| serde_json::to_value(v).ok()))
+    try_parse_nbt(data).ok()
+}
+
+/// Parse base64 gzipped NBT into JSON. The tag types are lost, see [`NbtTag::to_json`].
+pub fn try_parse_nbt(data: &str) -> Result<Value, Error> {
+    get_item_list(&NbtRoot::from_base64(data)?).map(NbtTag::to_json)
+}
+
+/// Parse gzipped NBT bytes, such as the backpack contents stored inside an item, into JSON
+pub fn parse_nbt_bytes(bytes: &[u8]) -> Result<Value, Error> {
+    get_item_list(&NbtRoot::from_bytes(bytes)?).map(NbtTag::to_json)
+}
+
+/// Parse base64 gzipped NBT into items. Empty slots are kept as `None`.
+pub fn parse_items(data: &str) -> Result<Vec<Option<SkyblockItem>>, Error> {
+    get_item_list(&NbtRoot::from_base64(data)?).map(SkyblockItem::from_nbt_list)
+}
+
+/// Parse gzipped NBT bytes into items. Empty slots are kept as `None`.
+pub fn parse_items_bytes(bytes: &[u8]) -> Result<Vec<Option<SkyblockItem>>, Error> {
+    get_item_list(&NbtRoot::from_bytes(bytes)?).map(SkyblockItem::from_nbt_list)
+}
+
+/// Encode items into base64 gzipped NBT using Hypixel's `{"i": [...]}` layout. Empty slots
+/// should be passed as empty objects. JSON has no tag types, so they are guessed as described in
+/// [`NbtTag::from_json`]. Use [`encode_items`] to keep the types of decoded items.
+pub fn encode_nbt(items: &[Value]) -> Result<String, Error> {
+    encode_nbt_bytes(items).map(|bytes| general_purpose::STANDARD.encode(bytes))
+}
+
+/// Encode items into gzipped NBT bytes, such as the backpack contents stored inside an item
+pub fn encode_nbt_bytes(items: &[Value]) -> Result<Vec<u8>, Error> {
+    to_item_list_root(
+        items
+            .iter()
+            .map(|item| NbtTag::from_json("", item))
+            .collect::<Result<_, _>>()?,
+    )
+    .to_gzip_bytes()
+}
+
+/// Encode decoded items back into base64 gzipped NBT. Empty slots are kept as `None`. Tags the
+/// typed fields do not cover are written back unchanged.
+pub fn encode_items(items: &[Option<SkyblockItem>]) -> Result<String, Error> {
+    to_item_list_root(
+        items
+            .iter()
+            .map(|item| {
+                item.as_ref().map_or_else(
+                    || NbtTag::Compound(NbtCompound::new()),
+                    SkyblockItem::to_nbt,
+                )
+            })
+            .collect(),
+    )
+    .to_base64()
+}
+
+/// Remove Minecraft `§` formatting codes from the text, see [`formatting::to_plain`]
+pub fn strip_formatting(text: &str) -> String {
+    formatting::to_plain(text)
+}
+
+/// Convert an id such as `GOLDEN_DRAGON` to a name such as `Golden Dragon`
+pub fn to_title_case(id: &str) -> String {
+    id.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect()
+            })
         })
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 pub fn get_timestamp_millis() -> i64 {
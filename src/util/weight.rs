@@ -0,0 +1,430 @@
+use super::{
+    leveling_tables::LevelingTables, skyblock_member::SkyblockDungeonType,
+    skyblock_profile::SkyblockProfile,
+};
+use crate::types::{dungeon_class::DungeonClass, skill::Skill, slayer::Slayer};
+use std::collections::HashMap;
+
+/// Weight with the part from exp past the max level kept separate
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Weight {
+    pub weight: f64,
+    pub overflow: f64,
+}
+
+impl Weight {
+    pub fn get_total(&self) -> f64 {
+        self.weight + self.overflow
+    }
+}
+
+impl std::ops::Add for Weight {
+    type Output = Weight;
+
+    fn add(self, other: Weight) -> Weight {
+        Weight {
+            weight: self.weight + other.weight,
+            overflow: self.overflow + other.overflow,
+        }
+    }
+}
+
+impl std::iter::Sum for Weight {
+    fn sum<I: Iterator<Item = Weight>>(iter: I) -> Weight {
+        iter.fold(Weight::default(), |a, b| a + b)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WeightBreakdown {
+    pub skills: HashMap<Skill, Weight>,
+    pub slayers: HashMap<Slayer, Weight>,
+    pub catacombs: Weight,
+    pub classes: HashMap<DungeonClass, Weight>,
+    /// Weight from Catacombs and Master Mode floor completions. Only Lily weight has this.
+    pub completions: Weight,
+}
+
+impl WeightBreakdown {
+    pub fn get_skills_weight(&self) -> Weight {
+        self.skills.values().copied().sum()
+    }
+
+    pub fn get_slayers_weight(&self) -> Weight {
+        self.slayers.values().copied().sum()
+    }
+
+    /// Get the catacombs, class, and completions weight
+    pub fn get_dungeons_weight(&self) -> Weight {
+        self.catacombs + self.classes.values().copied().sum() + self.completions
+    }
+
+    pub fn get_total(&self) -> Weight {
+        self.get_skills_weight() + self.get_slayers_weight() + self.get_dungeons_weight()
+    }
+}
+
+/// Constants for Senither weight. Skills, slayers, and classes without constants give no weight.
+#[derive(Debug, Clone)]
+pub struct SenitherWeightTables {
+    /// Bumped with every change to the constants, which the tests pin for each version
+    pub version: &'static str,
+    /// Exponent, overflow divider, and max level for each skill
+    pub skills: &'static [(Skill, f64, f64, i64)],
+    /// Divider and overflow modifier for each slayer
+    pub slayers: &'static [(Slayer, f64, f64)],
+    pub catacombs: f64,
+    pub classes: &'static [(DungeonClass, f64)],
+}
+
+pub const SENITHER_WEIGHT_V1: SenitherWeightTables = SenitherWeightTables {
+    version: "1",
+    skills: &[
+        (Skill::Mining, 1.18207448, 259_634.0, 60),
+        (Skill::Foraging, 1.232826, 259_634.0, 50),
+        (Skill::Enchanting, 0.96976583, 882_758.0, 60),
+        (Skill::Farming, 1.217848139, 220_689.0, 60),
+        (Skill::Combat, 1.15797687265, 275_862.0, 60),
+        (Skill::Fishing, 1.406418, 88_274.0, 50),
+        (Skill::Alchemy, 1.0, 1_103_448.0, 50),
+        (Skill::Taming, 1.14744, 441_379.0, 50),
+    ],
+    slayers: &[
+        (Slayer::Zombie, 2208.0, 0.15),
+        (Slayer::Spider, 2118.0, 0.08),
+        (Slayer::Wolf, 1962.0, 0.015),
+        (Slayer::Enderman, 1430.0, 0.017),
+    ],
+    catacombs: 0.0002149604615,
+    classes: &[
+        (DungeonClass::Healer, 0.0000045254834),
+        (DungeonClass::Mage, 0.0000045254834),
+        (DungeonClass::Berserk, 0.0000045254834),
+        (DungeonClass::Archer, 0.0000045254834),
+        (DungeonClass::Tank, 0.0000045254834),
+    ],
+};
+
+/// Constants for Lily weight. Skills, slayers, and floors without constants give no weight, and
+/// dungeon classes give none at all.
+#[derive(Debug, Clone)]
+pub struct LilyWeightTables {
+    /// Bumped with every change to the constants, which the tests pin for each version
+    pub version: &'static str,
+    /// Factor, overflow divider, and max level for each skill
+    pub skills: &'static [(Skill, f64, f64, i64)],
+    /// Exponent of the skill average, which scales the weight of every skill
+    pub skill_average_exponent: f64,
+    /// Weight of a maxed skill with a factor of 1 at skill average 60
+    pub skill_multiplier: f64,
+    /// Divider and deprecation scaling for each slayer. Every 100k exp counts for the scaling
+    /// times as much as the 100k before it.
+    pub slayers: &'static [(Slayer, f64, f64)],
+    pub catacombs: f64,
+    /// Worth of a completion of each Catacombs floor
+    pub completions: &'static [(i64, f64)],
+    /// Worth of a completion of each Master Mode floor
+    pub master_completions: &'static [(i64, f64)],
+    /// Completions of a floor past which each completion counts for less
+    pub completions_cap: f64,
+    /// Weight of having every Catacombs floor completed up to the cap
+    pub completions_weight: f64,
+    /// Weight of having every Master Mode floor completed up to the cap
+    pub master_completions_weight: f64,
+}
+
+pub const LILY_WEIGHT_V1: LilyWeightTables = LilyWeightTables {
+    version: "1",
+    skills: &[
+        (Skill::Mining, 1.68207448, 259_634.0, 60),
+        (Skill::Foraging, 1.732826, 259_634.0, 50),
+        (Skill::Enchanting, 1.46976583, 882_758.0, 60),
+        (Skill::Farming, 1.717848139, 220_689.0, 60),
+        (Skill::Combat, 1.65797687265, 275_862.0, 60),
+        (Skill::Fishing, 1.906418, 88_274.0, 50),
+        (Skill::Alchemy, 1.5, 1_103_448.0, 50),
+        (Skill::Taming, 1.64744, 441_379.0, 50),
+    ],
+    skill_average_exponent: 2.44780217148309,
+    skill_multiplier: 100.0,
+    slayers: &[
+        (Slayer::Zombie, 2208.0, 0.97),
+        (Slayer::Spider, 2118.0, 0.97),
+        (Slayer::Wolf, 1962.0, 0.98),
+        (Slayer::Enderman, 1430.0, 0.98),
+        (Slayer::Blaze, 1000.0, 0.98),
+    ],
+    catacombs: 0.0002149604615,
+    completions: &[
+        (0, 1.0),
+        (1, 2.0),
+        (2, 3.0),
+        (3, 4.0),
+        (4, 6.0),
+        (5, 8.0),
+        (6, 12.0),
+        (7, 16.0),
+    ],
+    master_completions: &[
+        (1, 24.0),
+        (2, 28.0),
+        (3, 32.0),
+        (4, 40.0),
+        (5, 48.0),
+        (6, 64.0),
+        (7, 80.0),
+    ],
+    completions_cap: 1000.0,
+    completions_weight: 1500.0,
+    master_completions_weight: 1000.0,
+};
+
+/// Slayer exp after which Senither weight counts as overflow
+const SENITHER_SLAYER_OVERFLOW_EXP: f64 = 1_000_000.0;
+
+/// Slayer exp that each Lily deprecation scaling step covers
+const LILY_SLAYER_EXP_STEP: f64 = 100_000.0;
+
+impl SkyblockProfile {
+    /// Get the Senither weight using the latest constants
+    pub fn get_senither_weight(&self, uuid: &str) -> WeightBreakdown {
+        self.get_senither_weight_with(uuid, &SENITHER_WEIGHT_V1)
+    }
+
+    pub fn get_senither_weight_with(
+        &self,
+        uuid: &str,
+        tables: &SenitherWeightTables,
+    ) -> WeightBreakdown {
+        let mut breakdown = WeightBreakdown::default();
+        let leveling_tables = LevelingTables::current();
+
+        for &(skill, exponent, divider, max_level) in tables.skills {
+            if let Some(leveling) = self.get_skill(uuid, skill, false) {
+                // Senither weight ignores the farming level cap
+                let leveling = SkyblockProfile::skill_exp_to_info(
+                    skill.name(),
+                    leveling.total_exp,
+                    leveling_tables.get_skill_table(skill),
+                    max_level,
+                    None,
+                );
+                let max_exp = leveling_tables
+                    .get_skill_table(skill)
+                    .iter()
+                    .take(max_level as usize)
+                    .sum::<i64>() as f64;
+                let exp = leveling.total_exp as f64;
+                let level = leveling.get_progress_level();
+
+                let mut weight = (level * 10.0).powf(0.5 + exponent + level / 100.0) / 1250.0;
+                let mut overflow = 0.0;
+                if exp > max_exp {
+                    weight = weight.round();
+                    overflow = ((exp - max_exp) / divider).powf(0.968);
+                }
+                breakdown.skills.insert(skill, Weight { weight, overflow });
+            }
+        }
+
+        for &(slayer, divider, modifier) in tables.slayers {
+            if let Some(leveling) = self.get_slayer(uuid, slayer, false) {
+                let exp = leveling.total_exp as f64;
+                let weight = if exp <= SENITHER_SLAYER_OVERFLOW_EXP {
+                    Weight {
+                        weight: exp / divider,
+                        overflow: 0.0,
+                    }
+                } else {
+                    let mut remaining = exp - SENITHER_SLAYER_OVERFLOW_EXP;
+                    let mut cur_modifier = modifier;
+                    let mut overflow = 0.0;
+                    while remaining > 0.0 {
+                        let left = remaining.min(SENITHER_SLAYER_OVERFLOW_EXP);
+                        overflow += (left / (divider * (1.5 + cur_modifier))).powf(0.942);
+                        cur_modifier += modifier;
+                        remaining -= left;
+                    }
+                    Weight {
+                        weight: SENITHER_SLAYER_OVERFLOW_EXP / divider,
+                        overflow,
+                    }
+                };
+                breakdown.slayers.insert(slayer, weight);
+            }
+        }
+
+        if let Some(leveling) = self.get_catacombs(uuid, false) {
+            breakdown.catacombs = Self::get_dungeon_weight(
+                leveling.get_progress_level(),
+                leveling.total_exp,
+                tables.catacombs,
+            );
+        }
+
+        for &(class, modifier) in tables.classes {
+            if let Some(leveling) = self.get_dungeon_class(uuid, class, false) {
+                breakdown.classes.insert(
+                    class,
+                    Self::get_dungeon_weight(
+                        leveling.get_progress_level(),
+                        leveling.total_exp,
+                        modifier,
+                    ),
+                );
+            }
+        }
+
+        breakdown
+    }
+
+    /// Get the Lily weight using the latest constants
+    pub fn get_lily_weight(&self, uuid: &str) -> WeightBreakdown {
+        self.get_lily_weight_with(uuid, &LILY_WEIGHT_V1)
+    }
+
+    /// Skill weight is scaled by the skill average, so skills without experience still count
+    /// towards it as level 0. Slayer weight has no overflow, since later exp is already worth
+    /// less.
+    pub fn get_lily_weight_with(&self, uuid: &str, tables: &LilyWeightTables) -> WeightBreakdown {
+        let mut breakdown = WeightBreakdown::default();
+        let leveling_tables = LevelingTables::current();
+
+        let skills = tables
+            .skills
+            .iter()
+            .map(|&(skill, factor, divider, max_level)| {
+                // Lily weight ignores the farming level cap
+                let exp = self
+                    .get_skill(uuid, skill, false)
+                    .map(|leveling| leveling.total_exp);
+                let level = exp.map_or(0, |exp| {
+                    SkyblockProfile::skill_exp_to_info(
+                        skill.name(),
+                        exp,
+                        leveling_tables.get_skill_table(skill),
+                        max_level,
+                        None,
+                    )
+                    .level
+                });
+                let max_exp = leveling_tables
+                    .get_skill_table(skill)
+                    .iter()
+                    .take(max_level as usize)
+                    .sum::<i64>() as f64;
+                (skill, factor, divider, level, exp, max_exp)
+            })
+            .collect::<Vec<_>>();
+        let average = skills.iter().map(|skill| skill.3 as f64).sum::<f64>()
+            / tables.skills.len().max(1) as f64;
+        let average_scale = 12.0 * (average / 60.0).powf(tables.skill_average_exponent);
+
+        for (skill, factor, divider, _, exp, max_exp) in skills {
+            if let Some(exp) = exp.map(|exp| exp as f64) {
+                let weight = tables.skill_multiplier
+                    * average_scale
+                    * factor
+                    * (exp.min(max_exp) / max_exp.max(1.0));
+                let overflow = if exp > max_exp {
+                    ((exp - max_exp) / divider).powf(0.968)
+                } else {
+                    0.0
+                };
+                breakdown.skills.insert(skill, Weight { weight, overflow });
+            }
+        }
+
+        for &(slayer, divider, scaling) in tables.slayers {
+            if let Some(leveling) = self.get_slayer(uuid, slayer, false) {
+                let mut remaining = leveling.total_exp as f64;
+                let mut worth = 1.0;
+                let mut effective_exp = 0.0;
+                while remaining > 0.0 {
+                    effective_exp += remaining.min(LILY_SLAYER_EXP_STEP) * worth;
+                    worth *= scaling;
+                    remaining -= LILY_SLAYER_EXP_STEP;
+                }
+                breakdown.slayers.insert(
+                    slayer,
+                    Weight {
+                        weight: effective_exp / divider,
+                        overflow: 0.0,
+                    },
+                );
+            }
+        }
+
+        if let Some(leveling) = self.get_catacombs(uuid, false) {
+            breakdown.catacombs = Self::get_dungeon_weight(
+                leveling.get_progress_level(),
+                leveling.total_exp,
+                tables.catacombs,
+            );
+        }
+
+        if let Some(dungeons) = self.get_dungeons(uuid) {
+            breakdown.completions = Weight {
+                weight: Self::get_lily_completions_weight(
+                    dungeons.get_catacombs(),
+                    tables.completions,
+                    tables,
+                ) * tables.completions_weight
+                    + Self::get_lily_completions_weight(
+                        dungeons.get_master_catacombs(),
+                        tables.master_completions,
+                        tables,
+                    ) * tables.master_completions_weight,
+                overflow: 0.0,
+            };
+        }
+
+        breakdown
+    }
+
+    /// Get the share of the completions weight, which is 1 with every floor completed up to the
+    /// cap. Completions past the cap count logarithmically.
+    fn get_lily_completions_weight(
+        dungeon: Option<&SkyblockDungeonType>,
+        worths: &[(i64, f64)],
+        tables: &LilyWeightTables,
+    ) -> f64 {
+        let max_score = worths.iter().map(|(_, worth)| worth).sum::<f64>() * tables.completions_cap;
+        let Some(dungeon) = dungeon.filter(|_| max_score > 0.0) else {
+            return 0.0;
+        };
+
+        worths
+            .iter()
+            .map(|&(floor, worth)| {
+                let completions = dungeon.get_completions(floor) as f64;
+                let mut score = completions.min(tables.completions_cap) * worth;
+                if completions > tables.completions_cap {
+                    let excess = completions - tables.completions_cap;
+                    score *= (excess / tables.completions_cap + 1.0).ln() / 7.5_f64.ln() + 1.0;
+                }
+                score
+            })
+            .sum::<f64>()
+            / max_score
+    }
+
+    fn get_dungeon_weight(level: f64, exp: i64, modifier: f64) -> Weight {
+        let max_exp = LevelingTables::current().catacombs.iter().sum::<i64>() as f64;
+        let exp = exp as f64;
+        let weight = level.powf(4.5) * modifier;
+
+        if exp <= max_exp {
+            return Weight {
+                weight,
+                overflow: 0.0,
+            };
+        }
+
+        let splitter = (4.0 * max_exp) / weight;
+        Weight {
+            weight: weight.floor(),
+            overflow: ((exp - max_exp) / splitter).powf(0.968),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use rs_pixel::{
+    response::skyblock::skyblock_auctions_response::SkyblockAuction,
+    types::{auction_category::AuctionCategory, rarity::Rarity},
+    util::{auction_query::AuctionQuery, utils::encode_nbt},
+};
+use serde_json::{json, Value};
+
+const NOW: i64 = 1_700_000_000_000;
+const SELLER: &str = "0123456789abcdef0123456789abcdef";
+
+fn auction(item: Value, overrides: Value) -> SkyblockAuction {
+    let mut auction = json!({
+        "uuid": "auction",
+        "auctioneer": SELLER,
+        "profile_id": SELLER,
+        "coop": [SELLER],
+        "start": NOW - 3_600_000,
+        "end": NOW + 3_600_000,
+        "item_name": "§6Withered Hyperion §d✪✪✪✪✪",
+        "item_lore": "",
+        "extra": "",
+        "category": "weapon",
+        "tier": "LEGENDARY",
+        "starting_bid": 1_000_000,
+        "item_bytes": encode_nbt(&[item]).unwrap(),
+        "claimed": false,
+        "bin": true,
+        "bids": [],
+    });
+    for (key, value) in overrides.as_object().unwrap() {
+        auction[key] = value.clone();
+    }
+    serde_json::from_value(auction).unwrap()
+}
+
+fn hyperion_nbt() -> Value {
+    json!({
+        "id": 267,
+        "Count": 1,
+        "Damage": 0,
+        "tag": {
+            "ExtraAttributes": {
+                "id": "HYPERION",
+                "modifier": "withered",
+                "upgrade_level": 5,
+                "enchantments": { "ultimate_wise": 5, "sharpness": 6 },
+            },
+        },
+    })
+}
+
+#[test]
+fn listing_filters() {
+    let hyperion = auction(hyperion_nbt(), json!({}));
+    let matches = |query: AuctionQuery| query.matches_at(&hyperion, NOW);
+
+    assert!(matches(AuctionQuery::new()));
+    assert!(matches(AuctionQuery::new().name("hyperion")));
+    assert!(!matches(AuctionQuery::new().name("valkyrie")));
+    // Formatting codes split the name, so they are stripped before matching
+    assert!(matches(AuctionQuery::new().name("hyperion ✪✪")));
+    assert!(matches(AuctionQuery::new().name("§6withered")));
+    assert!(matches(AuctionQuery::new().tier(Rarity::Legendary)));
+    assert!(!matches(AuctionQuery::new().tier(Rarity::Mythic)));
+    assert!(matches(
+        AuctionQuery::new().category(AuctionCategory::Weapon)
+    ));
+    assert!(!matches(
+        AuctionQuery::new().category(AuctionCategory::Armor)
+    ));
+    assert!(matches(AuctionQuery::new().bin_only(true)));
+    assert!(matches(AuctionQuery::new().min_price(1_000_000)));
+    assert!(!matches(AuctionQuery::new().min_price(1_000_001)));
+    assert!(matches(AuctionQuery::new().max_price(1_000_000)));
+    assert!(!matches(AuctionQuery::new().max_price(999_999)));
+    assert!(matches(AuctionQuery::new().ending_within(3_600_000)));
+    assert!(!matches(AuctionQuery::new().ending_within(60_000)));
+    assert!(matches(
+        AuctionQuery::new().seller("01234567-89AB-CDEF-0123-456789ABCDEF")
+    ));
+    assert!(!matches(AuctionQuery::new().seller("fedcba9876543210")));
+
+    let ended = auction(hyperion_nbt(), json!({ "end": NOW - 1 }));
+    assert!(!AuctionQuery::new()
+        .ending_within(3_600_000)
+        .matches_at(&ended, NOW));
+}
+
+#[test]
+fn bids_set_the_price() {
+    let auction = auction(
+        hyperion_nbt(),
+        json!({
+            "bin": false,
+            "bids": [
+                { "bidder": SELLER, "profile_id": SELLER, "amount": 1_500_000, "timestamp": NOW },
+                { "bidder": SELLER, "profile_id": SELLER, "amount": 2_000_000, "timestamp": NOW },
+            ],
+        }),
+    );
+
+    assert!(!AuctionQuery::new().bin_only(true).matches_at(&auction, NOW));
+    assert!(AuctionQuery::new()
+        .min_price(2_000_000)
+        .matches_at(&auction, NOW));
+    assert!(!AuctionQuery::new()
+        .max_price(1_500_000)
+        .matches_at(&auction, NOW));
+}
+
+#[test]
+fn item_filters() {
+    let hyperion = auction(hyperion_nbt(), json!({}));
+    let matches = |query: AuctionQuery| query.matches_at(&hyperion, NOW);
+
+    assert!(matches(AuctionQuery::new().item_id("HYPERION")));
+    assert!(!matches(AuctionQuery::new().item_id("VALKYRIE")));
+    assert!(matches(
+        AuctionQuery::new()
+            .enchantment("ULTIMATE_WISE", 5)
+            .enchantment("sharpness", 5)
+    ));
+    assert!(!matches(AuctionQuery::new().enchantment("sharpness", 7)));
+    assert!(!matches(AuctionQuery::new().enchantment("smite", 1)));
+    assert!(matches(AuctionQuery::new().reforge("Withered")));
+    assert!(!matches(AuctionQuery::new().reforge("fabled")));
+    assert!(matches(AuctionQuery::new().min_stars(5)));
+    assert!(!matches(AuctionQuery::new().min_stars(6)));
+
+    // Item filters never match an item that cannot be decoded
+    let broken = auction(hyperion_nbt(), json!({ "item_bytes": "not nbt" }));
+    assert!(AuctionQuery::new().matches_at(&broken, NOW));
+    assert!(!AuctionQuery::new()
+        .item_id("HYPERION")
+        .matches_at(&broken, NOW));
+}
+
+#[test]
+fn filter_page() {
+    let auctions = vec![
+        auction(hyperion_nbt(), json!({ "uuid": "a" })),
+        auction(hyperion_nbt(), json!({ "uuid": "b", "tier": "MYTHIC" })),
+        auction(hyperion_nbt(), json!({ "uuid": "c", "bin": false })),
+    ];
+    let query = AuctionQuery::new().bin_only(true).tier(Rarity::Legendary);
+
+    let matched = query
+        .filter(&auctions)
+        .into_iter()
+        .map(|auction| auction.uuid.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(matched, vec!["a"]);
+}
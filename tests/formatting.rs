@@ -0,0 +1,171 @@
+use rs_pixel::{
+    response::player_response::PlayerResponse,
+    types::rarity::Rarity,
+    util::formatting::{parse, to_ansi, to_html, to_plain, ChatColor, Style, StyledSpan},
+};
+use serde_json::{json, Value};
+
+const LORE: &str = "§6Gold §lbold§r plain §cred §nunder§mboth§zx§";
+
+fn player(player: Value) -> PlayerResponse {
+    serde_json::from_value(json!({ "success": true, "player": player })).unwrap()
+}
+
+#[test]
+fn parse_spans() {
+    let gold = Style {
+        color: Some(ChatColor::Gold),
+        ..Style::default()
+    };
+    let red = Style {
+        color: Some(ChatColor::Red),
+        ..Style::default()
+    };
+    let span = |text: &str, style| StyledSpan {
+        text: text.to_string(),
+        style,
+    };
+
+    assert_eq!(
+        parse(LORE),
+        vec![
+            span("Gold ", gold),
+            span("bold", Style { bold: true, ..gold }),
+            span(" plain ", Style::default()),
+            span("red ", red),
+            span(
+                "under",
+                Style {
+                    underlined: true,
+                    ..red
+                }
+            ),
+            span(
+                "bothx",
+                Style {
+                    underlined: true,
+                    strikethrough: true,
+                    ..red
+                }
+            ),
+        ]
+    );
+    // A color code resets the formatting
+    assert_eq!(
+        parse("§l§Abold?"),
+        vec![span(
+            "bold?",
+            Style {
+                color: Some(ChatColor::Green),
+                ..Style::default()
+            }
+        )]
+    );
+}
+
+#[test]
+fn render_plain() {
+    assert_eq!(to_plain(LORE), "Gold bold plain red underbothx");
+    assert_eq!(to_plain("no codes"), "no codes");
+    assert_eq!(to_plain("§5§o"), "");
+}
+
+#[test]
+fn render_ansi() {
+    assert_eq!(
+        to_ansi(LORE),
+        "\x1b[33mGold \x1b[0m\x1b[33;1mbold\x1b[0m plain \x1b[91mred \x1b[0m\x1b[91;4munder\x1b[0m\x1b[91;4;9mbothx\x1b[0m"
+    );
+    assert_eq!(to_ansi("§7§ogray"), "\x1b[37;3mgray\x1b[0m");
+}
+
+#[test]
+fn render_html() {
+    assert_eq!(
+        to_html(LORE),
+        "<span style=\"color:#FFAA00\">Gold </span>\
+         <span style=\"color:#FFAA00;font-weight:bold\">bold</span> plain \
+         <span style=\"color:#FF5555\">red </span>\
+         <span style=\"color:#FF5555;text-decoration:underline\">under</span>\
+         <span style=\"color:#FF5555;text-decoration:underline line-through\">bothx</span>"
+    );
+    assert_eq!(
+        to_html("§d<script>\"a\" & 'b'"),
+        "<span style=\"color:#FF55FF\">&lt;script&gt;&quot;a&quot; &amp; &#39;b&#39;</span>"
+    );
+}
+
+#[test]
+fn color_mappings() {
+    assert_eq!(ChatColor::from_name("dark_aqua"), Some(ChatColor::DarkAqua));
+    assert_eq!(ChatColor::from_name("PINK"), None);
+    assert_eq!(ChatColor::Gold.get_formatting_code(), "§6");
+    assert_eq!(Rarity::Legendary.get_color(), ChatColor::Gold);
+    assert_eq!(Rarity::Mythic.get_color().get_code(), 'd');
+}
+
+#[test]
+fn rank_prefixes() {
+    let prefix = |value| player(value).get_rank_prefix();
+
+    assert_eq!(prefix(json!({})), None);
+    assert_eq!(prefix(json!({ "packageRank": "VIP" })).unwrap(), "§a[VIP]");
+    assert_eq!(
+        prefix(json!({ "packageRank": "VIP_PLUS" })).unwrap(),
+        "§a[VIP§6+§a]"
+    );
+    assert_eq!(
+        prefix(json!({ "newPackageRank": "MVP" })).unwrap(),
+        "§b[MVP]"
+    );
+    assert_eq!(
+        prefix(json!({ "newPackageRank": "MVP_PLUS" })).unwrap(),
+        "§b[MVP§c+§b]"
+    );
+    assert_eq!(
+        prefix(json!({ "newPackageRank": "MVP_PLUS", "rankPlusColor": "DARK_GREEN" })).unwrap(),
+        "§b[MVP§2+§b]"
+    );
+    assert_eq!(
+        prefix(json!({
+            "newPackageRank": "MVP_PLUS",
+            "monthlyPackageRank": "SUPERSTAR",
+            "rankPlusColor": "BLACK",
+        }))
+        .unwrap(),
+        "§6[MVP§0++§6]"
+    );
+    assert_eq!(
+        prefix(json!({
+            "newPackageRank": "MVP_PLUS",
+            "monthlyPackageRank": "SUPERSTAR",
+            "monthlyRankColor": "AQUA",
+        }))
+        .unwrap(),
+        "§b[MVP§c++§b]"
+    );
+    // An expired subscription falls back to the package rank
+    assert_eq!(
+        prefix(json!({ "newPackageRank": "MVP_PLUS", "monthlyPackageRank": "NONE" })).unwrap(),
+        "§b[MVP§c+§b]"
+    );
+    assert_eq!(
+        prefix(json!({ "rank": "YOUTUBER", "newPackageRank": "MVP_PLUS" })).unwrap(),
+        "§c[§fYOUTUBE§c]"
+    );
+    assert_eq!(
+        prefix(json!({ "rank": "ADMIN", "newPackageRank": "MVP_PLUS" })).unwrap(),
+        "§c[ADMIN]"
+    );
+    assert_eq!(prefix(json!({ "rank": "GAME_MASTER" })).unwrap(), "§2[GM]");
+    assert_eq!(
+        prefix(json!({ "rank": "ADMIN", "prefix": "§c[OWNER]" })).unwrap(),
+        "§c[OWNER]"
+    );
+
+    assert_eq!(
+        player(json!({ "monthlyPackageRank": "SUPERSTAR" })).get_rank_color(),
+        Some(ChatColor::Gold)
+    );
+    assert_eq!(player(json!({})).get_rank_color(), None);
+}
@@ -0,0 +1,72 @@
+use rs_pixel::{
+    response::resources::skyblock_items_response::SkyblockItemsResponse,
+    types::rarity::Rarity,
+    util::{item_catalog::ItemCatalog, item_value::UpgradeCostSource},
+};
+
+fn load_catalog() -> ItemCatalog {
+    let path = format!(
+        "{}/tests/fixtures/skyblock_items.json",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let response: SkyblockItemsResponse =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    ItemCatalog::from_response(&response)
+}
+
+#[test]
+fn lookup() {
+    let catalog = load_catalog();
+    assert_eq!(catalog.len(), 4);
+
+    let hyperion = catalog.get("HYPERION").unwrap();
+    assert_eq!(hyperion.tier, Some(Rarity::Legendary));
+    assert_eq!(hyperion.get_dungeon_requirement(), Some(("CATACOMBS", 28)));
+    assert_eq!(catalog.get_npc_sell_price("HYPERION"), Some(100000.0));
+    assert_eq!(catalog.get_npc_sell_price("ASPECT_OF_THE_VOID"), None);
+
+    let compactor = catalog.get("PERSONAL_COMPACTOR_7000").unwrap();
+    assert_eq!(compactor.get_plain_name(), "Personal Compactor 7000");
+    assert!(compactor.is_soulbound() && compactor.museum);
+}
+
+#[test]
+fn fuzzy_name_lookup() {
+    let catalog = load_catalog();
+    let id = |name: &str| catalog.get_by_name(name).map(|item| item.id.as_str());
+
+    assert_eq!(id("hyperion"), Some("HYPERION"));
+    assert_eq!(
+        id("personal compactor 7000"),
+        Some("PERSONAL_COMPACTOR_7000")
+    );
+    assert_eq!(id("hyperoin"), Some("HYPERION"));
+    assert_eq!(id("end aspect"), Some("ASPECT_OF_THE_END"));
+    assert_eq!(id("nothing like it"), None);
+
+    let aspects = catalog.search_by_name("aspect of the");
+    assert_eq!(aspects.len(), 2);
+}
+
+#[test]
+fn upgrade_costs() {
+    let catalog = load_catalog();
+    assert_eq!(
+        catalog.get_star_costs("HYPERION", 2),
+        [
+            ("ESSENCE_WITHER".to_string(), 300),
+            ("WITHER_CATALYST".to_string(), 2)
+        ]
+    );
+    assert!(catalog.get_star_costs("HYPERION", 3).is_empty());
+    assert_eq!(
+        catalog.get_gemstone_slot_costs("HYPERION", "SAPPHIRE_0"),
+        [
+            ("COINS".to_string(), 250000),
+            ("FLAWLESS_SAPPHIRE_GEM".to_string(), 4)
+        ]
+    );
+    assert!(catalog
+        .get_gemstone_slot_costs("HYPERION", "SAPPHIRE_1")
+        .is_empty());
+}
@@ -0,0 +1,319 @@
+use rs_pixel::util::{
+    item_value::{
+        ItemValuationConfig, ItemValuator, ItemValue, UpgradeCostSource, ValueComponentKind,
+    },
+    skyblock_item::SkyblockItem,
+    skyblock_profile::PetStruct,
+    utils::{encode_nbt, parse_items},
+};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+struct StubCosts;
+
+impl UpgradeCostSource for StubCosts {
+    fn get_star_costs(&self, _item_id: &str, star: i64) -> Vec<(String, i64)> {
+        vec![("ESSENCE_WITHER".to_string(), star * 10)]
+    }
+
+    fn get_gemstone_slot_costs(&self, _item_id: &str, _slot: &str) -> Vec<(String, i64)> {
+        vec![
+            ("COINS".to_string(), 250_000),
+            ("FLAWLESS_JASPER_GEM".to_string(), 1),
+        ]
+    }
+}
+
+fn prices() -> HashMap<String, f64> {
+    [
+        ("HYPERION", 1_000_000_000.0),
+        ("ENCHANTED_BOOK", 10.0),
+        ("ENCHANTMENT_SHARPNESS_6", 100_000.0),
+        ("RECOMBOBULATOR_3000", 10_000_000.0),
+        ("HOT_POTATO_BOOK", 80_000.0),
+        ("FUMING_POTATO_BOOK", 1_000_000.0),
+        ("THE_ART_OF_WAR", 5_000_000.0),
+        ("ESSENCE_WITHER", 2_000.0),
+        ("FIRST_MASTER_STAR", 10_000_000.0),
+        ("SECOND_MASTER_STAR", 20_000_000.0),
+        ("PERFECT_JASPER_GEM", 15_000_000.0),
+        ("FINE_SAPPHIRE_GEM", 50_000.0),
+        ("FLAWLESS_JASPER_GEM", 3_000_000.0),
+        ("WITHER_BLOOD", 1_000_000.0),
+        ("PET_ENDERMAN_LEGENDARY", 20_000_000.0),
+        ("PET_ITEM_TIER_BOOST", 50_000_000.0),
+    ]
+    .into_iter()
+    .map(|(id, price)| (id.to_string(), price))
+    .collect()
+}
+
+fn item(extra_attributes: Value) -> SkyblockItem {
+    let data = encode_nbt(&[json!({
+        "id": 267,
+        "Count": 1,
+        "Damage": 0,
+        "tag": { "ExtraAttributes": extra_attributes },
+    })])
+    .unwrap();
+    parse_items(&data).unwrap().remove(0).unwrap()
+}
+
+fn hyperion() -> SkyblockItem {
+    item(json!({
+        "id": "HYPERION",
+        "modifier": "withered",
+        // No price for ultimate wise, so it adds nothing
+        "enchantments": { "sharpness": 6, "ultimate_wise": 5 },
+        "rarity_upgrades": 1,
+        "hot_potato_count": 15,
+        "art_of_war_count": 1,
+        "upgrade_level": 7,
+        "gems": {
+            "JASPER_0": "PERFECT",
+            "COMBAT_0": "FINE",
+            "COMBAT_0_gem": "SAPPHIRE",
+            "unlocked_slots": ["JASPER_0"],
+        },
+    }))
+}
+
+fn components(value: &ItemValue) -> Vec<(ValueComponentKind, &str, i64, f64)> {
+    value
+        .components
+        .iter()
+        .map(|c| (c.kind, c.item_id.as_str(), c.count, c.value))
+        .collect()
+}
+
+fn pet(exp: i64, candy_used: i64) -> PetStruct {
+    PetStruct::from_raw(&json!({
+        "type": "ENDERMAN",
+        "tier": "LEGENDARY",
+        "exp": exp,
+        "candyUsed": candy_used,
+        "heldItem": "PET_ITEM_TIER_BOOST",
+    }))
+    .unwrap()
+}
+
+#[test]
+fn item_valuation() {
+    let prices = prices();
+    let value = ItemValuator::new(&prices)
+        .upgrade_costs(&StubCosts)
+        .value_item(&hyperion())
+        .unwrap();
+
+    let mut gemstones = components(&value)
+        .into_iter()
+        .filter(|c| c.0 == ValueComponentKind::Gemstone)
+        .collect::<Vec<_>>();
+    gemstones.sort_by(|a, b| a.1.cmp(b.1));
+    assert_eq!(
+        gemstones,
+        vec![
+            (
+                ValueComponentKind::Gemstone,
+                "FINE_SAPPHIRE_GEM",
+                1,
+                50_000.0
+            ),
+            (
+                ValueComponentKind::Gemstone,
+                "PERFECT_JASPER_GEM",
+                1,
+                15_000_000.0
+            ),
+        ]
+    );
+
+    let expected = vec![
+        (
+            ValueComponentKind::Enchantment,
+            "ENCHANTMENT_SHARPNESS_6",
+            1,
+            85_000.0,
+        ),
+        (
+            ValueComponentKind::Recombobulator,
+            "RECOMBOBULATOR_3000",
+            1,
+            8_000_000.0,
+        ),
+        (
+            ValueComponentKind::HotPotatoBook,
+            "HOT_POTATO_BOOK",
+            10,
+            800_000.0,
+        ),
+        (
+            ValueComponentKind::FumingPotatoBook,
+            "FUMING_POTATO_BOOK",
+            5,
+            3_000_000.0,
+        ),
+        (
+            ValueComponentKind::ArtOfWar,
+            "THE_ART_OF_WAR",
+            1,
+            3_000_000.0,
+        ),
+        (
+            ValueComponentKind::DungeonStar,
+            "ESSENCE_WITHER",
+            10,
+            20_000.0,
+        ),
+        (
+            ValueComponentKind::DungeonStar,
+            "ESSENCE_WITHER",
+            20,
+            40_000.0,
+        ),
+        (
+            ValueComponentKind::DungeonStar,
+            "ESSENCE_WITHER",
+            30,
+            60_000.0,
+        ),
+        (
+            ValueComponentKind::DungeonStar,
+            "ESSENCE_WITHER",
+            40,
+            80_000.0,
+        ),
+        (
+            ValueComponentKind::DungeonStar,
+            "ESSENCE_WITHER",
+            50,
+            100_000.0,
+        ),
+        (
+            ValueComponentKind::GemstoneSlot,
+            "COINS",
+            250_000,
+            150_000.0,
+        ),
+        (
+            ValueComponentKind::GemstoneSlot,
+            "FLAWLESS_JASPER_GEM",
+            1,
+            1_800_000.0,
+        ),
+        (
+            ValueComponentKind::MasterStar,
+            "FIRST_MASTER_STAR",
+            1,
+            10_000_000.0,
+        ),
+        (
+            ValueComponentKind::MasterStar,
+            "SECOND_MASTER_STAR",
+            1,
+            20_000_000.0,
+        ),
+    ];
+    let mut actual = components(&value);
+    actual.retain(|c| c.0 != ValueComponentKind::Gemstone);
+    let reforge = actual.pop().unwrap();
+    assert_eq!(actual, expected);
+    assert_eq!(
+        reforge,
+        (ValueComponentKind::Reforge, "WITHER_BLOOD", 1, 1_000_000.0)
+    );
+
+    assert_eq!(value.base_price, Some(1_000_000_000.0));
+    assert_eq!(
+        value.total,
+        1_000_000_000.0 + value.components.iter().map(|c| c.value).sum::<f64>()
+    );
+
+    // Star and gemstone slot costs need a cost source
+    let value = ItemValuator::new(&prices).value_item(&hyperion()).unwrap();
+    assert!(!value.components.iter().any(|c| matches!(
+        c.kind,
+        ValueComponentKind::DungeonStar | ValueComponentKind::GemstoneSlot
+    )));
+
+    // A book's enchantment is its id, so it isn't counted again
+    let book = item(json!({ "id": "ENCHANTED_BOOK", "enchantments": { "sharpness": 6 } }));
+    let value = ItemValuator::new(&prices).value_item(&book).unwrap();
+    assert_eq!(value.item_id, "ENCHANTMENT_SHARPNESS_6");
+    assert_eq!((value.total, value.components.len()), (100_000.0, 0));
+
+    let unpriced = item(json!({ "id": "UNKNOWN_ITEM", "rarity_upgrades": 1 }));
+    let value = ItemValuator::new(&prices).value_item(&unpriced).unwrap();
+    assert_eq!(value.base_price, None);
+    assert_eq!(value.total, 8_000_000.0);
+
+    assert!(ItemValuator::new(&prices)
+        .value_item(&item(json!({})))
+        .is_none());
+}
+
+#[test]
+fn valuation_multipliers() {
+    let prices = prices();
+    let config = ItemValuationConfig {
+        enchantment: 1.0,
+        recombobulator: 0.5,
+        hot_potato_book: 0.5,
+        fuming_potato_book: 0.0,
+        art_of_war: 1.0,
+        dungeon_star: 0.5,
+        master_star: 0.25,
+        gemstone: 0.5,
+        gemstone_slot: 0.0,
+        reforge: 0.0,
+        pet_held_item: 0.5,
+        pet_candy: 0.5,
+    };
+    let valuator = ItemValuator::new(&prices)
+        .config(config)
+        .upgrade_costs(&StubCosts);
+    let value = valuator.value_item(&hyperion()).unwrap();
+    let total = |kind| {
+        value
+            .components
+            .iter()
+            .filter(|c| c.kind == kind)
+            .map(|c| c.value)
+            .sum::<f64>()
+    };
+
+    assert_eq!(total(ValueComponentKind::Enchantment), 100_000.0);
+    assert_eq!(total(ValueComponentKind::Recombobulator), 5_000_000.0);
+    assert_eq!(total(ValueComponentKind::HotPotatoBook), 400_000.0);
+    assert_eq!(total(ValueComponentKind::FumingPotatoBook), 0.0);
+    assert_eq!(total(ValueComponentKind::ArtOfWar), 5_000_000.0);
+    assert_eq!(total(ValueComponentKind::DungeonStar), 150_000.0);
+    assert_eq!(total(ValueComponentKind::MasterStar), 7_500_000.0);
+    assert_eq!(total(ValueComponentKind::Gemstone), 7_525_000.0);
+    assert_eq!(total(ValueComponentKind::GemstoneSlot), 0.0);
+    assert_eq!(total(ValueComponentKind::Reforge), 0.0);
+
+    // Candy lowers the value of a pet below max level by the kept share
+    let value = valuator.value_pet(&pet(0, 3));
+    assert_eq!(value.item_id, "PET_ENDERMAN_LEGENDARY");
+    assert_eq!(
+        components(&value),
+        vec![
+            (
+                ValueComponentKind::PetHeldItem,
+                "PET_ITEM_TIER_BOOST",
+                1,
+                25_000_000.0
+            ),
+            (ValueComponentKind::PetCandy, "PET_CANDY", 3, -10_000_000.0),
+        ]
+    );
+    assert_eq!(value.total, 35_000_000.0);
+
+    let maxed = valuator.value_pet(&pet(30_000_000, 3));
+    assert!(!maxed
+        .components
+        .iter()
+        .any(|c| c.kind == ValueComponentKind::PetCandy));
+    assert_eq!(maxed.total, 45_000_000.0);
+}
@@ -0,0 +1,65 @@
+//! Kept apart from the other tests since replacing the current tables affects every test running
+//! in the same binary.
+
+use rs_pixel::{
+    types::{skill::Skill, slayer::Slayer},
+    util::{leveling_tables::LevelingTables, skyblock_profile::SkyblockProfile},
+};
+use serde_json::json;
+
+const UUID: &str = "0123456789abcdef0123456789abcdef";
+
+#[test]
+fn set_current() {
+    let profile: SkyblockProfile = serde_json::from_value(json!({
+        "profile_id": "4d5e6f7a8b9c4d0e8f1a2b3c4d5e6f70",
+        "members": { UUID: {
+            "experience_skill_combat": 700,
+            "slayer_bosses": { "zombie": { "xp": 50 } },
+            "pets": [{ "type": "TIGER", "tier": "LEGENDARY", "exp": 1_000 }],
+        } }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        profile.get_skill(UUID, Skill::Combat, false).unwrap().level,
+        4
+    );
+    assert_eq!(
+        profile
+            .get_slayer(UUID, Slayer::Zombie, false)
+            .unwrap()
+            .level,
+        2
+    );
+    assert_eq!(profile.get_pets(UUID).unwrap()[0].level, 2);
+    assert_eq!(Skill::Combat.get_max_level(), 60);
+    assert_eq!(Slayer::Zombie.get_leveling_table()[0], 5);
+
+    let mut tables = LevelingTables::default();
+    tables.skills.insert(Skill::Combat, vec![100, 200, 300]);
+    tables.skill_max_levels.insert(Skill::Combat, 3);
+    tables.slayers.insert(Slayer::Zombie, vec![10, 100]);
+    tables.pets = vec![100; 200];
+    tables.pet_max_levels.insert("TIGER".to_string(), 150);
+    LevelingTables::set_current(tables);
+
+    let combat = profile.get_skill(UUID, Skill::Combat, false).unwrap();
+    assert_eq!((combat.level, combat.max_level), (3, 3));
+    let zombie = profile.get_slayer(UUID, Slayer::Zombie, false).unwrap();
+    assert_eq!((zombie.level, zombie.max_level), (1, 2));
+    let tiger = &profile.get_pets(UUID).unwrap()[0];
+    assert_eq!((tiger.level, tiger.max_level), (11, 150));
+
+    // The type helpers read the current tables too
+    assert_eq!(Skill::Combat.get_leveling_table(), vec![100, 200, 300]);
+    assert_eq!(Skill::Combat.get_max_level(), 3);
+    assert_eq!(Slayer::Zombie.get_leveling_table(), vec![10, 100]);
+    assert_eq!(Slayer::Zombie.get_max_level(), 2);
+
+    LevelingTables::set_current(LevelingTables::default());
+    assert_eq!(
+        profile.get_skill(UUID, Skill::Combat, false).unwrap().level,
+        4
+    );
+}
@@ -0,0 +1,289 @@
+use flate2::{write::GzEncoder, Compression};
+use rs_pixel::util::{
+    error::Error,
+    nbt_tag::{NbtCompound, NbtRoot, NbtTag},
+    utils::{self, encode_nbt, try_parse_nbt},
+};
+use serde_json::json;
+use std::io::Write;
+
+/// Wrap a single tag as `{"": {"v": tag}}`
+fn root(tag: NbtTag) -> NbtRoot {
+    let mut compound = NbtCompound::new();
+    compound.insert("v", tag);
+    NbtRoot {
+        name: String::new(),
+        compound,
+    }
+}
+
+/// Raw bytes of `{"": {"v": payload}}` for a tag type
+fn root_bytes(type_id: u8, payload: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![10, 0, 0, type_id, 0, 1, b'v'];
+    bytes.extend(payload);
+    bytes.push(0);
+    bytes
+}
+
+fn gzip(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bytes).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn nbt_error(result: Result<NbtRoot, Error>) -> String {
+    match result {
+        Err(Error::Nbt(err)) => err,
+        other => panic!("Expected an NBT error but got {other:?}"),
+    }
+}
+
+fn cases() -> Vec<(u8, Vec<u8>, NbtTag)> {
+    let mut compound = NbtCompound::new();
+    compound.insert("a", NbtTag::Byte(1));
+    vec![
+        (1, vec![0xff], NbtTag::Byte(-1)),
+        (2, vec![0x01, 0x02], NbtTag::Short(258)),
+        (3, vec![0xff, 0xff, 0xff, 0xfe], NbtTag::Int(-2)),
+        (
+            4,
+            1_650_000_000_000_i64.to_be_bytes().to_vec(),
+            NbtTag::Long(1_650_000_000_000),
+        ),
+        (5, 1.5_f32.to_be_bytes().to_vec(), NbtTag::Float(1.5)),
+        (6, 0.1_f64.to_be_bytes().to_vec(), NbtTag::Double(0.1)),
+        (
+            7,
+            vec![0, 0, 0, 2, 0x7f, 0x80],
+            NbtTag::ByteArray(vec![127, -128]),
+        ),
+        (
+            8,
+            vec![0, 3, b'a', b'b', b'c'],
+            NbtTag::String("abc".to_string()),
+        ),
+        (
+            9,
+            vec![8, 0, 0, 0, 1, 0, 1, b'x'],
+            NbtTag::List(vec![NbtTag::String("x".to_string())]),
+        ),
+        (9, vec![0, 0, 0, 0, 0], NbtTag::List(vec![])),
+        (10, vec![1, 0, 1, b'a', 1, 0], NbtTag::Compound(compound)),
+        (
+            11,
+            vec![0, 0, 0, 2, 0, 0, 0, 1, 0xff, 0xff, 0xff, 0xff],
+            NbtTag::IntArray(vec![1, -1]),
+        ),
+        (
+            12,
+            [vec![0, 0, 0, 1], 7_i64.to_be_bytes().to_vec()].concat(),
+            NbtTag::LongArray(vec![7]),
+        ),
+    ]
+}
+
+#[test]
+fn decode_tags() {
+    for (type_id, payload, tag) in cases() {
+        let decoded = NbtRoot::from_bytes(&root_bytes(type_id, &payload)).unwrap();
+        assert_eq!(decoded.get("v"), Some(&tag), "tag type {type_id}");
+    }
+}
+
+#[test]
+fn encode_tags() {
+    for (type_id, payload, tag) in cases() {
+        assert_eq!(
+            root(tag).to_bytes().unwrap(),
+            root_bytes(type_id, &payload),
+            "tag type {type_id}"
+        );
+    }
+}
+
+#[test]
+fn round_trip() {
+    let mut compound = NbtCompound::new();
+    for (idx, (_, _, tag)) in cases().into_iter().enumerate() {
+        compound.insert(format!("tag_{idx}"), tag);
+    }
+    compound.insert("unicode", NbtTag::String("✪ §6Hyperion \0 🐉".to_string()));
+    let nbt = NbtRoot {
+        name: "root".to_string(),
+        compound,
+    };
+
+    assert_eq!(NbtRoot::from_bytes(&nbt.to_bytes().unwrap()).unwrap(), nbt);
+    assert_eq!(
+        NbtRoot::from_bytes(&nbt.to_gzip_bytes().unwrap()).unwrap(),
+        nbt
+    );
+    assert_eq!(
+        NbtRoot::from_base64(&nbt.to_base64().unwrap()).unwrap(),
+        nbt
+    );
+}
+
+#[test]
+fn compound_paths() {
+    let nbt = NbtRoot::from_bytes(&root_bytes(
+        9,
+        &[10, 0, 0, 0, 1, 3, 0, 1, b'n', 0, 0, 0, 5, 0],
+    ))
+    .unwrap();
+    assert_eq!(nbt.compound.get_int_property("v.0.n"), Some(5));
+    assert!(nbt.compound.get_property("v.1.n").is_none());
+    assert!(nbt.compound.get_property("v.x").is_none());
+}
+
+#[test]
+fn malformed_input() {
+    // Truncated in the middle of an int
+    assert_eq!(
+        nbt_error(NbtRoot::from_bytes(&[10, 0, 0, 3, 0, 1, b'v', 0, 0])),
+        "Unexpected end of data at byte 7"
+    );
+    assert_eq!(
+        nbt_error(NbtRoot::from_bytes(&root_bytes(3, &[0, 0, 0, 1])[..11])),
+        "Unexpected end of data at byte 11"
+    );
+    assert_eq!(
+        nbt_error(NbtRoot::from_bytes(&[8, 0, 0])),
+        "Expected a root compound but found tag type 8"
+    );
+    assert_eq!(
+        nbt_error(NbtRoot::from_bytes(&root_bytes(13, &[]))),
+        "Invalid tag type 13 at byte 7"
+    );
+    assert_eq!(
+        nbt_error(NbtRoot::from_bytes(&root_bytes(
+            7,
+            &[0xff, 0xff, 0xff, 0xff]
+        ))),
+        "Negative length at byte 7"
+    );
+    assert_eq!(
+        nbt_error(NbtRoot::from_bytes(&root_bytes(9, &[0, 0, 0, 0, 1]))),
+        "Non-empty list without an element type at byte 12"
+    );
+    assert_eq!(
+        nbt_error(NbtRoot::from_bytes(
+            &[root_bytes(1, &[1]), vec![0]].concat()
+        )),
+        "Found 1 trailing bytes after the root compound"
+    );
+    assert!(
+        nbt_error(NbtRoot::from_bytes(&root_bytes(8, &[0, 1, 0xff]))).starts_with("Invalid string")
+    );
+
+    let nested = |depth: usize| [[10, 0, 0].repeat(depth + 1), vec![0; depth + 1]].concat();
+    assert!(NbtRoot::from_bytes(&nested(127)).is_ok());
+    assert_eq!(
+        nbt_error(NbtRoot::from_bytes(&nested(200))),
+        "Exceeded the maximum depth of 128"
+    );
+
+    assert!(matches!(
+        NbtRoot::from_base64("not base64!"),
+        Err(Error::Base64(_))
+    ));
+    assert!(matches!(
+        NbtRoot::from_bytes(&[0x1f, 0x8b, 0, 0]),
+        Err(Error::Gzip(_))
+    ));
+}
+
+#[test]
+fn decompression_is_capped() {
+    let bomb = gzip(&vec![0; 16 * 1024 * 1024 + 1]);
+    assert!(nbt_error(NbtRoot::from_bytes(&bomb)).starts_with("Decompressed data exceeds"));
+}
+
+#[test]
+fn invalid_writes() {
+    let long_string = root(NbtTag::String("a".repeat(65_536)));
+    assert_eq!(
+        match long_string.to_bytes() {
+            Err(Error::Nbt(err)) => err,
+            other => panic!("Expected an NBT error but got {other:?}"),
+        },
+        "String of 65536 bytes exceeds the maximum of 65535 bytes"
+    );
+    assert!(root(NbtTag::String("a".repeat(65_535))).to_bytes().is_ok());
+
+    let mixed_list = root(NbtTag::List(vec![NbtTag::Int(1), NbtTag::Long(2)]));
+    assert!(matches!(
+        mixed_list.to_gzip_bytes(),
+        Err(Error::Nbt(err)) if err == "List mixes tag types 3 and 4"
+    ));
+}
+
+#[test]
+fn json_conversion_loses_tag_types() {
+    let mut item = NbtCompound::new();
+    for (key, tag) in [
+        ("Count", NbtTag::Byte(1)),
+        ("id", NbtTag::Short(267)),
+        ("hideInfo", NbtTag::Byte(0)),
+        ("level", NbtTag::Short(5)),
+        ("exp", NbtTag::Float(0.5)),
+        ("timestamp", NbtTag::Long(5)),
+        ("big", NbtTag::Long(1 << 40)),
+        ("ints", NbtTag::IntArray(vec![1, 2])),
+        ("backpack_data", NbtTag::ByteArray(vec![1, 2])),
+    ] {
+        item.insert(key, tag);
+    }
+    let data = NbtRoot {
+        name: String::new(),
+        compound: {
+            let mut compound = NbtCompound::new();
+            compound.insert("i", NbtTag::List(vec![NbtTag::Compound(item)]));
+            compound
+        },
+    }
+    .to_base64()
+    .unwrap();
+
+    let json = try_parse_nbt(&data).unwrap();
+    assert_eq!(json[0]["exp"], json!(0.5));
+    let encoded = NbtRoot::from_base64(&encode_nbt(json.as_array().unwrap()).unwrap()).unwrap();
+    let item = encoded.compound.get_property("i.0").unwrap();
+    let item = item.as_compound().unwrap();
+
+    // Types recovered from the key names
+    assert_eq!(item.get("Count"), Some(&NbtTag::Byte(1)));
+    assert_eq!(item.get("id"), Some(&NbtTag::Short(267)));
+    assert_eq!(item.get("timestamp"), Some(&NbtTag::Long(5)));
+    assert_eq!(item.get("big"), Some(&NbtTag::Long(1 << 40)));
+    assert_eq!(
+        item.get("backpack_data"),
+        Some(&NbtTag::ByteArray(vec![1, 2]))
+    );
+    // Types that are lost
+    assert_eq!(item.get("hideInfo"), Some(&NbtTag::Int(0)));
+    assert_eq!(item.get("level"), Some(&NbtTag::Int(5)));
+    assert_eq!(item.get("exp"), Some(&NbtTag::Double(0.5)));
+    assert_eq!(
+        item.get("ints"),
+        Some(&NbtTag::List(vec![NbtTag::Int(1), NbtTag::Int(2)]))
+    );
+
+    // Missing item list
+    assert!(matches!(
+        try_parse_nbt(&root(NbtTag::Byte(1)).to_base64().unwrap()),
+        Err(Error::Nbt(_))
+    ));
+    assert!(matches!(
+        try_parse_nbt("not base64!"),
+        Err(Error::Base64(_))
+    ));
+}
+
+#[test]
+#[allow(deprecated)]
+fn parse_nbt_option() {
+    let data = encode_nbt(&[json!({ "id": 267, "Count": 1 })]).unwrap();
+    assert_eq!(utils::parse_nbt(&data), Some(try_parse_nbt(&data).unwrap()));
+    assert_eq!(utils::parse_nbt("not base64!"), None);
+}
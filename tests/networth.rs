@@ -0,0 +1,186 @@
+use rs_pixel::{
+    response::skyblock::skyblock_auctions_response::SkyblockAuction,
+    util::{
+        networth::{LowestBinPriceSource, NetworthCategory, PriceSource},
+        skyblock_profile::SkyblockProfile,
+        utils::encode_nbt,
+    },
+};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const UUID: &str = "0123456789abcdef0123456789abcdef";
+
+/// Fixed prices, so values don't depend on the bazaar or auctions
+struct StubPrices(&'static [(&'static str, f64)]);
+
+impl PriceSource for StubPrices {
+    fn get_price(&self, item_id: &str) -> Option<f64> {
+        self.0
+            .iter()
+            .find(|(id, _)| *id == item_id)
+            .map(|(_, price)| *price)
+    }
+}
+
+const PRICES: StubPrices = StubPrices(&[
+    ("HYPERION", 1_000_000_000.0),
+    ("ENCHANTMENT_SHARPNESS_6", 100_000.0),
+    ("ENCHANTED_DIAMOND", 1_500.0),
+    ("HOT_POTATO_BOOK", 80_000.0),
+    ("PET_GOLDEN_DRAGON_LEGENDARY", 600_000_000.0),
+    ("PET_ITEM_TIER_BOOST", 50_000_000.0),
+]);
+
+fn item(id: &str, count: i64, extra_attributes: Value) -> Value {
+    let mut extra_attributes = extra_attributes;
+    extra_attributes["id"] = json!(id);
+    json!({
+        "id": 267,
+        "Count": count,
+        "Damage": 0,
+        "tag": { "ExtraAttributes": extra_attributes },
+    })
+}
+
+fn profile() -> SkyblockProfile {
+    let inventory = encode_nbt(&[
+        item(
+            "HYPERION",
+            1,
+            json!({ "enchantments": { "sharpness": 6 }, "hot_potato_count": 2 }),
+        ),
+        json!({}),
+        item("ENCHANTED_DIAMOND", 64, json!({})),
+        item("UNKNOWN_ITEM", 1, json!({})),
+    ])
+    .unwrap();
+    let ender_chest = encode_nbt(&[item("ENCHANTED_DIAMOND", 10, json!({}))]).unwrap();
+
+    serde_json::from_value(json!({
+        "profile_id": "4d5e6f7a8b9c4d0e8f1a2b3c4d5e6f70",
+        "banking": { "balance": 2_000_000.0, "transactions": [] },
+        "members": { UUID: {
+            "coin_purse": 500.5,
+            "bank_account": 1_000.0,
+            "inv_contents": { "type": 0, "data": inventory },
+            "ender_chest_contents": { "type": 0, "data": ender_chest },
+            "sacks_counts": { "ENCHANTED_DIAMOND": 100, "ROTTEN_FLESH": 5, "SAND": 0 },
+            "pets": [
+                { "type": "GOLDEN_DRAGON", "tier": "LEGENDARY", "exp": 0, "heldItem": "PET_ITEM_TIER_BOOST" },
+            ],
+        } }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn networth_breakdown() {
+    let networth = profile().get_networth(UUID, &PRICES);
+
+    assert_eq!(networth.get_category_total(NetworthCategory::Purse), 500.5);
+    let bank = &networth.categories[&NetworthCategory::Bank];
+    assert_eq!(bank.total, 2_001_000.0);
+    assert_eq!(bank.items.len(), 2);
+
+    // Empty slots are skipped and unpriced items are listed separately
+    let inventory = &networth.categories[&NetworthCategory::Inventory];
+    assert_eq!(inventory.unpriced, vec!["UNKNOWN_ITEM"]);
+    assert_eq!(inventory.items.len(), 2);
+    let hyperion = &inventory.items[0];
+    assert_eq!(hyperion.item_id, "HYPERION");
+    assert_eq!(hyperion.components.len(), 2);
+    assert_eq!(
+        hyperion.value,
+        1_000_000_000.0 + 100_000.0 * 0.85 + 2.0 * 80_000.0
+    );
+    let diamonds = &inventory.items[1];
+    assert_eq!((diamonds.count, diamonds.value), (64, 64.0 * 1_500.0));
+    assert_eq!(inventory.total, hyperion.value + diamonds.value);
+
+    assert_eq!(
+        networth.get_category_total(NetworthCategory::EnderChest),
+        15_000.0
+    );
+
+    let sacks = &networth.categories[&NetworthCategory::Sacks];
+    assert_eq!(sacks.total, 150_000.0);
+    assert_eq!(sacks.unpriced, vec!["ROTTEN_FLESH"]);
+
+    let pets = &networth.categories[&NetworthCategory::Pets];
+    assert_eq!(pets.items[0].item_id, "PET_GOLDEN_DRAGON_LEGENDARY");
+    assert_eq!(pets.total, 650_000_000.0);
+
+    // Missing inventories are left out instead of counting as empty
+    assert!(!networth
+        .categories
+        .contains_key(&NetworthCategory::Wardrobe));
+    assert_eq!(
+        networth.total,
+        networth
+            .categories
+            .values()
+            .map(|breakdown| breakdown.total)
+            .sum::<f64>()
+    );
+}
+
+#[test]
+fn price_source_fallback() {
+    let mut first = HashMap::new();
+    first.insert("ENCHANTED_DIAMOND".to_string(), 1_000.0);
+    let prices = (first, PRICES);
+
+    // The first source wins, and the second fills in what it lacks
+    assert_eq!(prices.get_price("ENCHANTED_DIAMOND"), Some(1_000.0));
+    assert_eq!(prices.get_price("HYPERION"), Some(1_000_000_000.0));
+    assert_eq!(prices.get_price("UNKNOWN_ITEM"), None);
+
+    let networth = profile().get_networth(UUID, &prices);
+    assert_eq!(
+        networth.get_category_total(NetworthCategory::EnderChest),
+        10_000.0
+    );
+    assert_eq!(
+        networth.get_category_total(NetworthCategory::Sacks),
+        100_000.0
+    );
+}
+
+#[test]
+fn lowest_bin_prices() {
+    let auction = |item: Value, price: i64, bin: bool| -> SkyblockAuction {
+        serde_json::from_value(json!({
+            "uuid": "auction",
+            "auctioneer": UUID,
+            "profile_id": UUID,
+            "coop": [UUID],
+            "start": 0,
+            "end": 1,
+            "item_name": "",
+            "item_lore": "",
+            "extra": "",
+            "category": "misc",
+            "tier": "COMMON",
+            "starting_bid": price,
+            "item_bytes": encode_nbt(&[item]).unwrap(),
+            "claimed": false,
+            "bin": bin,
+            "bids": [],
+        }))
+        .unwrap()
+    };
+
+    let mut source = LowestBinPriceSource::new();
+    source.insert_auctions(&[
+        auction(item("HYPERION", 1, json!({})), 900, true),
+        auction(item("HYPERION", 1, json!({})), 800, true),
+        // Not BIN, so the starting bid isn't a price
+        auction(item("HYPERION", 1, json!({})), 1, false),
+        // Priced per item
+        auction(item("ENCHANTED_DIAMOND", 4, json!({})), 400, true),
+    ]);
+    assert_eq!(source.get_price("HYPERION"), Some(800.0));
+    assert_eq!(source.get_price("ENCHANTED_DIAMOND"), Some(100.0));
+    assert_eq!(source.get_price("UNKNOWN_ITEM"), None);
+}
@@ -0,0 +1,124 @@
+use rs_pixel::util::price_tracker::{
+    FileSaleStorage, InMemorySaleStorage, PriceTracker, PriceWindow, SaleStorage, TrackedSale,
+};
+
+const NOW: i64 = 1_700_000_000_000;
+const MINUTE: i64 = 60_000;
+
+fn sale(auction_id: &str, item_id: &str, price: i64, count: i64, age: i64) -> TrackedSale {
+    TrackedSale {
+        auction_id: auction_id.to_string(),
+        item_id: item_id.to_string(),
+        price,
+        count,
+        timestamp: NOW - age,
+        bin: true,
+    }
+}
+
+fn tracker() -> PriceTracker<InMemorySaleStorage> {
+    let mut storage = InMemorySaleStorage::new();
+    for sale in [
+        sale("a", "ENCHANTED_DIAMOND", 1_000, 1, 10 * MINUTE),
+        sale("b", "ENCHANTED_DIAMOND", 3_000, 2, 30 * MINUTE),
+        sale("c", "ENCHANTED_DIAMOND", 500, 1, 2 * 60 * MINUTE),
+        sale("d", "ENCHANTED_DIAMOND", 7_000, 1, 3 * 24 * 60 * MINUTE),
+        sale("e", "ENCHANTED_DIAMOND", 9_000, 1, 8 * 24 * 60 * MINUTE),
+        sale("f", "HYPERION", 1_000_000_000, 1, 5 * MINUTE),
+        // Sold after the window end, so never counted
+        sale("g", "ENCHANTED_DIAMOND", 100, 1, -MINUTE),
+    ] {
+        storage.insert(sale).unwrap();
+    }
+    PriceTracker::new(storage)
+}
+
+#[test]
+fn aggregation() {
+    let stats = tracker()
+        .get_stats_at("ENCHANTED_DIAMOND", PriceWindow::Hour, NOW)
+        .unwrap();
+    assert_eq!(stats.item_id, "ENCHANTED_DIAMOND");
+    assert_eq!(stats.window, PriceWindow::Hour);
+    assert_eq!(stats.sales, 2);
+    assert_eq!(stats.volume, 3);
+    // Weighted by count: (1000 + 3000) / 3 items
+    assert!((stats.average - 4_000.0 / 3.0).abs() < 1e-9);
+    assert_eq!(stats.min, 1_000.0);
+    assert_eq!(stats.max, 1_500.0);
+
+    assert!(tracker()
+        .get_stats_at("UNKNOWN", PriceWindow::Week, NOW)
+        .is_none());
+}
+
+#[test]
+fn windowing() {
+    let tracker = tracker();
+    let stats = |window| {
+        tracker
+            .get_stats_at("ENCHANTED_DIAMOND", window, NOW)
+            .unwrap()
+    };
+
+    assert_eq!(stats(PriceWindow::Hour).sales, 2);
+    assert_eq!(stats(PriceWindow::Day).sales, 3);
+    assert_eq!(stats(PriceWindow::Day).min, 500.0);
+    assert_eq!(stats(PriceWindow::Week).sales, 4);
+    assert_eq!(stats(PriceWindow::Week).max, 7_000.0);
+
+    // Moving the window end back drops the newest sales
+    let earlier = tracker
+        .get_stats_at("ENCHANTED_DIAMOND", PriceWindow::Hour, NOW - 20 * MINUTE)
+        .unwrap();
+    assert_eq!(earlier.sales, 1);
+    assert_eq!(earlier.average, 1_500.0);
+    assert!(tracker
+        .get_stats_at(
+            "ENCHANTED_DIAMOND",
+            PriceWindow::Hour,
+            NOW - 4 * 60 * MINUTE
+        )
+        .is_none());
+}
+
+#[test]
+fn storage_dedup_and_pruning() {
+    let mut storage = InMemorySaleStorage::new();
+    storage
+        .insert(sale("a", "ENCHANTED_DIAMOND", 1, 1, 0))
+        .unwrap();
+    storage
+        .insert(sale("a", "ENCHANTED_DIAMOND", 2, 1, 0))
+        .unwrap();
+    storage
+        .insert(sale("b", "ENCHANTED_DIAMOND", 3, 1, 2 * MINUTE))
+        .unwrap();
+    storage
+        .insert(sale("c", "HYPERION", 4, 1, 3 * MINUTE))
+        .unwrap();
+    assert_eq!(storage.get_sales("ENCHANTED_DIAMOND").len(), 2);
+    assert_eq!(storage.get_sales("ENCHANTED_DIAMOND")[0].price, 1);
+
+    storage.remove_before(NOW - MINUTE).unwrap();
+    assert_eq!(storage.get_sales("ENCHANTED_DIAMOND").len(), 1);
+    assert!(storage.get_sales("HYPERION").is_empty());
+    assert_eq!(storage.get_item_ids(), vec!["ENCHANTED_DIAMOND"]);
+    assert!(storage.contains("a"));
+    assert!(!storage.contains("b"));
+}
+
+#[test]
+fn file_storage_persists() {
+    let path = std::env::temp_dir().join(format!("rs_pixel_sales_{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut storage = FileSaleStorage::open(&path).unwrap();
+    storage.insert(sale("a", "HYPERION", 10, 1, 0)).unwrap();
+    storage.flush().unwrap();
+
+    let storage = FileSaleStorage::open(&path).unwrap();
+    assert!(storage.contains("a"));
+    assert_eq!(storage.get_sales("HYPERION")[0].price, 10);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,213 @@
+use rs_pixel::{
+    response::resources::{
+        achievements_response::AchievementsResponse, challenges_response::ChallengesResponse,
+        games_response::GamesResponse, guild_achievements_response::GuildAchievementsResponse,
+        quests_response::QuestsResponse, skyblock_bingo_response::SkyblockBingoResourceResponse,
+        skyblock_election_response::SkyblockElectionResponse,
+        skyblock_items_response::SkyblockItemsResponse,
+        skyblock_skills_response::SkyblockSkillsResponse, vanity_response::VanityResponse,
+    },
+    types::rarity::Rarity,
+};
+use serde_json::json;
+
+fn load<T: serde::de::DeserializeOwned>(name: &str) -> T {
+    let path = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn games() {
+    let response: GamesResponse = load("games");
+    assert_eq!(response.last_updated, 1700000000000);
+    assert_eq!(response.games.len(), 3);
+
+    let bedwars = &response.games["BEDWARS"];
+    assert_eq!((bedwars.id, bedwars.name.as_str()), (58, "Bed Wars"));
+    assert_eq!(bedwars.database_name.as_deref(), Some("Bedwars"));
+    assert_eq!(bedwars.mode_names["EIGHT_TWO"], "Doubles");
+    assert!(!bedwars.retired && !bedwars.legacy);
+    assert!(response.games["QUAKECRAFT"].retired);
+
+    let limbo = &response.games["LIMBO"];
+    assert_eq!(limbo.database_name, None);
+    assert!(limbo.mode_names.is_empty());
+}
+
+#[test]
+fn achievements() {
+    let response: AchievementsResponse = load("achievements");
+    let bedwars = &response.achievements["bedwars"];
+    assert_eq!(bedwars.total_points, 1270);
+
+    let beds = &bedwars.one_time["BEDS"];
+    assert_eq!((beds.name.as_str(), beds.points), ("Bed Destroyer", 5));
+    assert_eq!(beds.game_percent_unlocked, Some(62.5));
+    let secret = &bedwars.one_time["SECRET_SPOT"];
+    assert!(secret.secret);
+    assert_eq!((secret.points, secret.global_percent_unlocked), (0, None));
+
+    let level = &bedwars.tiered["LEVEL"];
+    assert_eq!(level.tiers.len(), 2);
+    assert_eq!((level.tiers[1].amount, level.tiers[1].points), (500, 10));
+
+    let legacy = &response.achievements["truecombat"];
+    assert!(legacy.tiered.is_empty());
+    assert!(legacy.one_time["FIRST_KILL"].legacy);
+    assert_eq!(legacy.total_legacy_points, 10);
+}
+
+#[test]
+fn challenges() {
+    let response: ChallengesResponse = load("challenges");
+    assert_eq!(response.challenges["ARCADE"][0].name, "Zombies Challenge");
+
+    let bedwars = &response.challenges["BEDWARS"];
+    assert_eq!(bedwars.len(), 2);
+    assert_eq!(bedwars[0].rewards[0].amount, 2500);
+    assert_eq!(bedwars[0].rewards[1].reward_type, "CoinReward");
+    assert_eq!(bedwars[0].rewards[1].amount, 0);
+    assert!(bedwars[1].rewards.is_empty());
+}
+
+#[test]
+fn quests() {
+    let response: QuestsResponse = load("quests");
+    let bedwars = &response.quests["bedwars"];
+
+    let daily = &bedwars[0];
+    assert_eq!(daily.id, "bedwars_daily_win");
+    assert_eq!(daily.rewards[0].amount, 5000);
+    assert_eq!(daily.objectives[0].integer, Some(1));
+    assert_eq!(
+        daily.requirements[0].requirement_type,
+        "DailyResetQuestRequirement"
+    );
+
+    let weekly = &bedwars[1];
+    assert_eq!(weekly.objectives[1].objective_type, "BooleanObjective");
+    assert_eq!(weekly.objectives[1].integer, None);
+    assert_eq!(weekly.requirements[1].other["gameType"], "BEDWARS");
+}
+
+#[test]
+fn guild_achievements() {
+    let response: GuildAchievementsResponse = load("guild_achievements");
+    assert!(response.one_time["PRESTIGE"].tiers.is_empty());
+
+    let tiers = &response.tiered["EXPERIENCE_KINGS"].tiers;
+    assert_eq!(
+        tiers.iter().map(|tier| tier.amount).collect::<Vec<_>>(),
+        vec![5000, 10000, 25000]
+    );
+    assert_eq!(tiers[0].points, 0);
+}
+
+#[test]
+fn vanity() {
+    let pets: VanityResponse = load("vanity_pets");
+    assert_eq!(pets.types.len(), 3);
+    assert_eq!(pets.types[0].rarity, Some(Rarity::Common));
+    assert_eq!(pets.types[0].package.as_deref(), Some("cat_black"));
+    let herobrine = &pets.types[2];
+    assert_eq!((&herobrine.name, &herobrine.package), (&None, &None));
+    assert_eq!(pets.rarities[3].name, Rarity::Legendary);
+    assert_eq!(pets.rarities[3].color, "GOLD");
+
+    let companions: VanityResponse = load("vanity_companions");
+    assert_eq!(companions.types[0].rarity, Some(Rarity::Epic));
+    assert_eq!(companions.types[1].rarity, None);
+    assert_eq!(companions.types[1].package, None);
+}
+
+#[test]
+fn skyblock_bingo() {
+    let response: SkyblockBingoResourceResponse = load("skyblock_bingo");
+    assert_eq!((response.id, response.name.as_str()), (30, "April 2024"));
+    assert_eq!(response.modifier.as_deref(), Some("EXTREME"));
+
+    let personal = &response.goals[0];
+    assert!(personal
+        .lore
+        .as_deref()
+        .unwrap()
+        .contains("Great White Shark"));
+    assert_eq!(personal.full_lore.len(), 3);
+    assert!(personal.tiers.is_empty() && personal.progress.is_none());
+
+    let community = &response.goals[1];
+    assert_eq!(community.lore, None);
+    assert_eq!(community.tiers.len(), 3);
+    assert_eq!(community.progress, Some(7400000));
+    assert_eq!(community.required_amount, Some(25000000));
+
+    // Older events have no modifier
+    let older: SkyblockBingoResourceResponse = serde_json::from_value(json!({
+        "success": true,
+        "lastUpdated": 1700000000000_i64,
+        "id": 1,
+        "name": "June 2022",
+        "start": 1654041600000_i64,
+        "end": 1654646400000_i64,
+        "goals": [],
+    }))
+    .unwrap();
+    assert_eq!(older.modifier, None);
+}
+
+#[test]
+fn skyblock_election() {
+    let response: SkyblockElectionResponse = load("skyblock_election");
+    assert_eq!(response.mayor.election.as_ref().unwrap().year, 300);
+
+    // Special mayors can be missing the election and perk details
+    let special: SkyblockElectionResponse = serde_json::from_value(json!({
+        "success": true,
+        "lastUpdated": 1700000000000_i64,
+        "mayor": {
+            "key": "derpy",
+            "name": "Derpy",
+            "perks": [{ "name": "TURBO MINIONS!!!" }],
+        },
+    }))
+    .unwrap();
+    assert!(special.mayor.election.is_none() && special.current.is_none());
+    assert!(special.is_perk_active("turbo minions!!!"));
+    assert_eq!(special.mayor.perks[0].description, "");
+}
+
+#[test]
+fn skyblock_items() {
+    let response: SkyblockItemsResponse = load("skyblock_items");
+    assert_eq!(response.items.len(), 4);
+
+    let minimal: SkyblockItemsResponse = serde_json::from_value(json!({
+        "success": true,
+        "lastUpdated": 1700000000000_i64,
+        "items": [{ "id": "ROTTEN_FLESH", "material": "ROTTEN_FLESH", "name": "Rotten Flesh" }],
+    }))
+    .unwrap();
+    let item = &minimal.items[0];
+    assert_eq!((item.tier, item.npc_sell_price), (None, None));
+    assert!(item.stats.is_empty() && item.upgrade_costs.is_empty());
+    assert!(!item.is_soulbound() && !item.museum);
+}
+
+#[test]
+fn skyblock_skills() {
+    let response: SkyblockSkillsResponse = load("skyblock_skills");
+    assert_eq!(response.version.as_deref(), Some("0.11.54"));
+    assert_eq!(response.skills["COMBAT"].levels.len(), 3);
+
+    let unversioned: SkyblockSkillsResponse = serde_json::from_value(json!({
+        "success": true,
+        "lastUpdated": 1700000000000_i64,
+        "skills": {
+            "SOCIAL": { "name": "Social", "maxLevel": 1, "levels": [{ "level": 1, "totalExpRequired": 50.0 }] },
+        },
+    }))
+    .unwrap();
+    assert_eq!(unversioned.version, None);
+    assert_eq!(unversioned.skills["SOCIAL"].description, "");
+    assert!(unversioned.skills["SOCIAL"].levels[0].unlocks.is_empty());
+}
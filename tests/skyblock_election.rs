@@ -0,0 +1,74 @@
+use rs_pixel::{
+    response::resources::skyblock_election_response::SkyblockElectionResponse,
+    util::skyblock_calendar::{SkyblockDate, SKYBLOCK_EPOCH_MILLIS, SKYBLOCK_YEAR_MILLIS},
+};
+
+fn load_election() -> SkyblockElectionResponse {
+    let path = format!(
+        "{}/tests/fixtures/skyblock_election.json",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn perks() {
+    let election = load_election();
+    assert_eq!(election.get_active_perks().len(), 3);
+    assert!(election.is_perk_active("fishing festival"));
+    assert!(election.is_perk_active("Mining Fiesta"));
+    // Candidates' perks aren't active until they win
+    assert!(!election.is_perk_active("Marauder"));
+    assert!(election.is_mayor_active("marina") && election.is_mayor_active("mining"));
+    assert!(!election.is_mayor_active("dungeons"));
+}
+
+#[test]
+fn vote_shares() {
+    let election = load_election();
+    let shares = election.mayor.election.as_ref().unwrap().get_vote_shares();
+    assert_eq!(
+        election.mayor.election.as_ref().unwrap().get_total_votes(),
+        1000
+    );
+    assert_eq!(shares[0].0.name, "Marina");
+    assert_eq!(shares[0].1, 60.0);
+    assert_eq!(shares[2].1, 10.0);
+    assert_eq!(
+        election
+            .mayor
+            .election
+            .as_ref()
+            .unwrap()
+            .get_leader()
+            .unwrap()
+            .key,
+        "fishing"
+    );
+
+    // Votes are hidden during the election
+    let current = election.current.unwrap();
+    assert_eq!(current.get_total_votes(), 0);
+    assert!(current
+        .get_vote_shares()
+        .iter()
+        .all(|(_, share)| *share == 0.0));
+}
+
+#[test]
+fn calendar() {
+    let date = SkyblockDate::from_timestamp(SKYBLOCK_EPOCH_MILLIS);
+    assert_eq!((date.year, date.month, date.day), (1, 1, 1));
+
+    let date = SkyblockDate {
+        year: 301,
+        month: 3,
+        day: 27,
+    };
+    assert_eq!(SkyblockDate::from_timestamp(date.to_timestamp()), date);
+    assert_eq!(date.to_string(), "Late Spring 27, Year 301");
+    assert_eq!(
+        SkyblockDate::from_timestamp(SKYBLOCK_EPOCH_MILLIS + 300 * SKYBLOCK_YEAR_MILLIS - 1).year,
+        300
+    );
+}
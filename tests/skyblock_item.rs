@@ -0,0 +1,382 @@
+//! Hypixel's item NBT cannot be fetched offline, so the fixtures are built with the same tag
+//! types and layout as the `i` list in inventory and auction data.
+
+use rs_pixel::util::{
+    nbt_tag::{NbtCompound, NbtRoot, NbtTag},
+    skyblock_item::SkyblockGemstone,
+    utils::{encode_items, parse_items},
+};
+
+fn compound(entries: Vec<(&str, NbtTag)>) -> NbtTag {
+    let mut compound = NbtCompound::new();
+    for (key, tag) in entries {
+        compound.insert(key, tag);
+    }
+    NbtTag::Compound(compound)
+}
+
+fn string(value: &str) -> NbtTag {
+    NbtTag::String(value.to_string())
+}
+
+fn item(id: i16, display_name: &str, extra_attributes: Vec<(&str, NbtTag)>) -> NbtTag {
+    compound(vec![
+        ("id", NbtTag::Short(id)),
+        ("Count", NbtTag::Byte(1)),
+        (
+            "tag",
+            compound(vec![
+                (
+                    "display",
+                    compound(vec![
+                        ("Name", string(display_name)),
+                        (
+                            "Lore",
+                            NbtTag::List(vec![string("§7Damage: §c+270"), string("")]),
+                        ),
+                    ]),
+                ),
+                ("ExtraAttributes", compound(extra_attributes)),
+            ]),
+        ),
+        ("Damage", NbtTag::Short(0)),
+    ])
+}
+
+fn encode(items: Vec<NbtTag>) -> String {
+    let mut compound = NbtCompound::new();
+    compound.insert("i", NbtTag::List(items));
+    NbtRoot {
+        name: String::new(),
+        compound,
+    }
+    .to_base64()
+    .unwrap()
+}
+
+fn hyperion() -> NbtTag {
+    item(
+        267,
+        "§dHeroic Hyperion §6✪✪✪✪✪",
+        vec![
+            ("rarity_upgrades", NbtTag::Int(1)),
+            ("hot_potato_count", NbtTag::Int(15)),
+            ("modifier", string("heroic")),
+            ("upgrade_level", NbtTag::Int(5)),
+            ("id", string("HYPERION")),
+            (
+                "enchantments",
+                compound(vec![
+                    ("ultimate_wise", NbtTag::Int(5)),
+                    ("sharpness", NbtTag::Int(6)),
+                ]),
+            ),
+            ("uuid", string("9f6a3cbe-3c2d-4c3c-a1a2-5a7d1b0c3e4f")),
+            ("timestamp", NbtTag::Long(1_650_000_000_000)),
+            ("originTag", string("CRAFTING_GRID_COLLECT")),
+            (
+                "gems",
+                compound(vec![
+                    ("SAPPHIRE_0", string("PERFECT")),
+                    (
+                        "COMBAT_0",
+                        compound(vec![
+                            ("uuid", string("e1b2")),
+                            ("quality", string("FLAWLESS")),
+                        ]),
+                    ),
+                    ("COMBAT_0_gem", string("JASPER")),
+                    (
+                        "unlocked_slots",
+                        NbtTag::List(vec![string("COMBAT_0"), string("SAPPHIRE_0")]),
+                    ),
+                ]),
+            ),
+        ],
+    )
+}
+
+fn pet() -> NbtTag {
+    item(
+        397,
+        "§f§7[Lvl 100] §6Ender Dragon",
+        vec![
+            (
+                "petInfo",
+                string(
+                    r#"{"type":"ENDER_DRAGON","active":false,"exp":25353230.0,"tier":"LEGENDARY","hideInfo":false,"heldItem":"PET_ITEM_TIER_BOOST","candyUsed":0,"uuid":"3c7e"}"#,
+                ),
+            ),
+            ("id", string("PET")),
+            ("uuid", string("3c7e")),
+            ("timestamp", NbtTag::Long(1_650_000_000_000)),
+        ],
+    )
+}
+
+#[test]
+fn decode_item() {
+    let items = parse_items(&encode(vec![hyperion()])).unwrap();
+    let item = items[0].as_ref().unwrap();
+
+    assert_eq!(item.id, 267);
+    assert_eq!(item.count, 1);
+    assert_eq!(item.damage, 0);
+    assert_eq!(item.name.as_deref(), Some("§dHeroic Hyperion §6✪✪✪✪✪"));
+    assert_eq!(item.lore, vec!["§7Damage: §c+270", ""]);
+    assert_eq!(item.skyblock_id.as_deref(), Some("HYPERION"));
+    assert_eq!(item.get_canonical_id().as_deref(), Some("HYPERION"));
+    assert_eq!(
+        item.uuid.as_deref(),
+        Some("9f6a3cbe-3c2d-4c3c-a1a2-5a7d1b0c3e4f")
+    );
+    assert_eq!(item.reforge.as_deref(), Some("heroic"));
+    assert_eq!(item.enchantments.len(), 2);
+    assert_eq!(item.enchantments["ultimate_wise"], 5);
+    assert_eq!(item.enchantments["sharpness"], 6);
+    assert_eq!(item.dungeon_stars, 5);
+    assert_eq!(item.hot_potato_count, 15);
+    assert!(item.recombobulated);
+    assert_eq!(item.rarity_upgrades, 1);
+    assert_eq!(item.timestamp, Some(1_650_000_000_000));
+    assert_eq!(item.origin_tag.as_deref(), Some("CRAFTING_GRID_COLLECT"));
+    assert!(item.pet_info.is_none());
+    assert!(item.backpack_contents.is_none());
+
+    let mut gemstones = item.gemstones.clone();
+    gemstones.sort_by(|a, b| a.slot.cmp(&b.slot));
+    assert_eq!(
+        gemstones,
+        vec![
+            SkyblockGemstone {
+                slot: "COMBAT_0".to_string(),
+                gem_type: "JASPER".to_string(),
+                quality: "FLAWLESS".to_string(),
+            },
+            SkyblockGemstone {
+                slot: "SAPPHIRE_0".to_string(),
+                gem_type: "SAPPHIRE".to_string(),
+                quality: "PERFECT".to_string(),
+            },
+        ]
+    );
+    assert_eq!(item.unlocked_gemstone_slots, vec!["COMBAT_0", "SAPPHIRE_0"]);
+}
+
+#[test]
+fn decode_pet() {
+    let items = parse_items(&encode(vec![pet()])).unwrap();
+    let item = items[0].as_ref().unwrap();
+
+    assert_eq!(item.skyblock_id.as_deref(), Some("PET"));
+    assert_eq!(
+        item.get_canonical_id().as_deref(),
+        Some("PET_ENDER_DRAGON_LEGENDARY")
+    );
+    let pet_info = item.pet_info.as_ref().unwrap();
+    assert_eq!(pet_info["type"], "ENDER_DRAGON");
+    assert_eq!(pet_info["tier"], "LEGENDARY");
+    assert_eq!(pet_info["exp"], 25_353_230.0);
+    assert_eq!(pet_info["heldItem"], "PET_ITEM_TIER_BOOST");
+    assert!(item.enchantments.is_empty());
+    assert_eq!(item.dungeon_stars, 0);
+    assert!(item.reforge.is_none());
+}
+
+#[test]
+fn decode_inventory() {
+    let legacy_stars = item(
+        261,
+        "§5Spirit Bow",
+        vec![
+            ("id", string("ITEM_SPIRIT_BOW")),
+            ("dungeon_item_level", NbtTag::Int(3)),
+            ("timestamp", string("6/9/21 3:44 PM")),
+        ],
+    );
+    let book = item(
+        403,
+        "§fEnchanted Book",
+        vec![
+            ("id", string("ENCHANTED_BOOK")),
+            (
+                "enchantments",
+                compound(vec![("sharpness", NbtTag::Int(6))]),
+            ),
+        ],
+    );
+    let items = parse_items(&encode(vec![
+        hyperion(),
+        compound(vec![]),
+        pet(),
+        legacy_stars,
+        book,
+    ]))
+    .unwrap();
+
+    assert_eq!(items.len(), 5);
+    assert!(items[1].is_none());
+
+    let spirit_bow = items[3].as_ref().unwrap();
+    assert_eq!(spirit_bow.dungeon_stars, 3);
+    assert!(spirit_bow.timestamp.is_none());
+    assert!(!spirit_bow.recombobulated);
+
+    assert_eq!(
+        items[4].as_ref().unwrap().get_canonical_id().as_deref(),
+        Some("ENCHANTMENT_SHARPNESS_6")
+    );
+}
+
+#[test]
+fn encode_round_trip() {
+    let items = parse_items(&encode(vec![hyperion(), compound(vec![]), pet()])).unwrap();
+    let decoded = parse_items(&encode_items(&items).unwrap()).unwrap();
+
+    assert_eq!(decoded.len(), 3);
+    assert!(decoded[1].is_none());
+    for (item, decoded) in [(&items[0], &decoded[0]), (&items[2], &decoded[2])] {
+        let (item, decoded) = (item.as_ref().unwrap(), decoded.as_ref().unwrap());
+        assert_eq!(decoded.id, item.id);
+        assert_eq!(decoded.name, item.name);
+        assert_eq!(decoded.lore, item.lore);
+        assert_eq!(decoded.get_canonical_id(), item.get_canonical_id());
+        assert_eq!(decoded.reforge, item.reforge);
+        assert_eq!(decoded.enchantments, item.enchantments);
+        assert_eq!(decoded.dungeon_stars, item.dungeon_stars);
+        assert_eq!(decoded.gemstones, item.gemstones);
+        assert_eq!(decoded.timestamp, item.timestamp);
+        assert_eq!(decoded.pet_info, item.pet_info);
+    }
+
+    // Edits to the typed fields are written back
+    let mut hyperion = items[0].clone().unwrap();
+    hyperion.reforge = Some("fabled".to_string());
+    hyperion.enchantments.insert("sharpness".to_string(), 7);
+    hyperion.dungeon_stars = 3;
+    hyperion.rarity_upgrades = 0;
+    let decoded = parse_items(&encode_items(&[Some(hyperion)]).unwrap()).unwrap();
+    let decoded = decoded[0].as_ref().unwrap();
+    assert_eq!(decoded.reforge.as_deref(), Some("fabled"));
+    assert_eq!(decoded.enchantments["sharpness"], 7);
+    assert_eq!(decoded.dungeon_stars, 3);
+    assert!(!decoded.recombobulated);
+}
+
+#[test]
+fn encode_keeps_tag_types() {
+    let mut pet_item = pet();
+    if let NbtTag::Compound(item) = &mut pet_item {
+        item.insert("Unbreakable", NbtTag::Byte(1));
+    }
+    let mut hyperion = hyperion();
+    if let NbtTag::Compound(item) = &mut hyperion {
+        let mut extra = item
+            .get_compound_property("tag.ExtraAttributes")
+            .unwrap()
+            .clone();
+        extra.insert("upgrade_level", NbtTag::Short(5));
+        extra.insert("stats_book", NbtTag::Float(1.5));
+        extra.insert("champion_combat_xp", NbtTag::Double(1.5));
+        extra.insert("ints", NbtTag::IntArray(vec![1, 2]));
+        extra.insert("new_year_cake_bag_data", NbtTag::ByteArray(vec![1, -1]));
+        let mut tag = item.get_compound_property("tag").unwrap().clone();
+        tag.insert("ExtraAttributes", NbtTag::Compound(extra));
+        tag.insert("HideFlags", NbtTag::Int(254));
+        item.insert("tag", NbtTag::Compound(tag));
+    }
+    let original = vec![hyperion, compound(vec![]), pet_item];
+
+    // Unchanged items are written back exactly as decoded
+    let items = parse_items(&encode(original.clone())).unwrap();
+    assert_eq!(
+        items[0].as_ref().unwrap().to_nbt(),
+        original[0],
+        "item is unchanged"
+    );
+    assert_eq!(encode_items(&items).unwrap(), encode(original.clone()));
+
+    // Changed fields keep the tag type they were decoded with
+    let mut hyperion = items[0].clone().unwrap();
+    hyperion.dungeon_stars = 4;
+    hyperion.count = 2;
+    hyperion.enchantments.insert("smite".to_string(), 7);
+    let NbtTag::Compound(nbt) = hyperion.to_nbt() else {
+        panic!("Expected a compound");
+    };
+    assert_eq!(nbt.get("Count"), Some(&NbtTag::Byte(2)));
+    assert_eq!(
+        nbt.get_property("tag.ExtraAttributes.upgrade_level"),
+        Some(&NbtTag::Short(4))
+    );
+    assert_eq!(
+        nbt.get_property("tag.ExtraAttributes.stats_book"),
+        Some(&NbtTag::Float(1.5))
+    );
+    assert_eq!(
+        nbt.get_property("tag.ExtraAttributes.enchantments.smite"),
+        Some(&NbtTag::Int(7))
+    );
+    let enchantments = nbt
+        .get_compound_property("tag.ExtraAttributes.enchantments")
+        .unwrap()
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(enchantments, vec!["ultimate_wise", "sharpness", "smite"]);
+}
+
+#[test]
+fn decode_backpack_contents() {
+    let contents = |items: Vec<NbtTag>| {
+        let mut compound = NbtCompound::new();
+        compound.insert("i", NbtTag::List(items));
+        let bytes = NbtRoot {
+            name: String::new(),
+            compound,
+        }
+        .to_gzip_bytes()
+        .unwrap();
+        NbtTag::ByteArray(bytes.into_iter().map(|b| b as i8).collect())
+    };
+    let backpack = |id: &str| {
+        item(
+            397,
+            "§6Jumbo Backpack",
+            vec![
+                // Decoded before the backpack keys when walking the map in order
+                ("other_data", contents(vec![pet()])),
+                ("small_backpack_data", contents(vec![pet(), pet()])),
+                ("id", string(id)),
+                ("jumbo_backpack_data", contents(vec![hyperion()])),
+            ],
+        )
+    };
+
+    // The key named after the item wins over other backpack keys
+    let items = parse_items(&encode(vec![backpack("JUMBO_BACKPACK")])).unwrap();
+    let contents = items[0]
+        .as_ref()
+        .unwrap()
+        .backpack_contents
+        .as_ref()
+        .unwrap();
+    assert_eq!(contents.len(), 1);
+    assert_eq!(
+        contents[0].as_ref().unwrap().skyblock_id.as_deref(),
+        Some("HYPERION")
+    );
+
+    // Otherwise the first known backpack key is used, never an unrelated `_data` key
+    let items = parse_items(&encode(vec![backpack("UNKNOWN_BAG")])).unwrap();
+    assert_eq!(
+        items[0]
+            .as_ref()
+            .unwrap()
+            .backpack_contents
+            .as_ref()
+            .unwrap()
+            .len(),
+        2
+    );
+}
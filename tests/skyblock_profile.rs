@@ -0,0 +1,510 @@
+use rs_pixel::{
+    response::resources::{
+        skyblock_collections_response::SkyblockCollectionsResponse,
+        skyblock_skills_response::SkyblockSkillsResponse,
+    },
+    types::{dungeon_class::DungeonClass, rarity::Rarity, skill::Skill, slayer::Slayer},
+    util::{
+        leveling_tables::LevelingTables,
+        skyblock_profile::{PetStruct, SkyblockProfile},
+    },
+};
+
+const UUID: &str = "0123456789abcdef0123456789abcdef";
+
+fn load<T: serde::de::DeserializeOwned>(name: &str) -> T {
+    let path = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn assert_profile(profile: &SkyblockProfile) {
+    assert_eq!(profile.get_purse_coins(UUID), Some(12345.5));
+    assert_eq!(profile.get_fairy_souls(UUID), 200);
+    assert_eq!(profile.get_farming_cap_upgrade(UUID), 10);
+    assert_eq!(profile.get_minion_slots(), 5);
+
+    let farming = profile.get_skill(UUID, Skill::Farming, false).unwrap();
+    assert_eq!(farming.max_level, 60);
+    assert_eq!(farming.level, 50);
+    assert_eq!(
+        profile
+            .get_skill(UUID, Skill::Combat, false)
+            .unwrap()
+            .total_exp,
+        1500000
+    );
+    assert_eq!(
+        profile
+            .get_skill(UUID, Skill::Social, false)
+            .unwrap()
+            .total_exp,
+        3000
+    );
+    assert!(profile.get_skill(UUID, Skill::Mining, false).is_none());
+
+    assert_eq!(
+        profile
+            .get_slayer(UUID, Slayer::Zombie, false)
+            .unwrap()
+            .level,
+        9
+    );
+    assert_eq!(
+        profile
+            .get_slayer(UUID, Slayer::Wolf, false)
+            .unwrap()
+            .total_exp,
+        5000
+    );
+    assert_eq!(profile.get_hotm(UUID).unwrap().total_exp, 150000);
+
+    let inventory = profile.get_inventory(UUID).unwrap();
+    assert_eq!(inventory.len(), 2);
+    let item = inventory[0].as_ref().unwrap();
+    assert_eq!(item.skyblock_id.as_deref(), Some("ASPECT_OF_THE_END"));
+    assert_eq!(item.reforge.as_deref(), Some("heroic"));
+    assert!(inventory[1].is_none());
+    assert_eq!(profile.get_storage(UUID).unwrap()["0"].len(), 2);
+    assert_eq!(profile.get_sacks(UUID).unwrap()["ENCHANTED_WHEAT"], 64);
+
+    let skills = profile.get_all_skills(UUID, false);
+    assert_eq!(skills.len(), 3);
+    assert_eq!(skills[&Skill::Combat].name, "combat");
+    let slayers = profile.get_all_slayers(UUID, false);
+    assert_eq!(slayers.len(), 2);
+    assert!(!slayers.contains_key(&Slayer::Vampire));
+    assert_eq!(
+        profile.get_slayer_boss_kills(UUID, Slayer::Zombie),
+        Some([0, 0, 0, 0, 150])
+    );
+    let summary = profile.get_slayer_summary(UUID).unwrap();
+    assert_eq!(summary.slayers.len(), 2);
+    assert_eq!(summary.total_exp, 1_005_000);
+    assert_eq!(summary.total_boss_kills, 150);
+    assert_eq!(summary.coins_spent, 150 * 100_000);
+
+    let catacombs = profile.get_catacombs(UUID, false).unwrap();
+    assert_eq!((catacombs.level, catacombs.overflow_level), (50, 0));
+    let catacombs = profile.get_catacombs(UUID, true).unwrap();
+    assert_eq!(catacombs.level, 50);
+    assert_eq!(catacombs.overflow_level, 1);
+    assert_eq!(catacombs.overflow_exp, 300_000_000);
+    assert_eq!(catacombs.current_exp, 100_000_000);
+    assert_eq!(catacombs.get_progress_level(), 51.5);
+    assert_eq!(profile.get_all_classes(UUID, true).len(), 1);
+
+    let dungeons = profile.get_dungeons(UUID).unwrap();
+    let catacombs = dungeons.get_catacombs().unwrap();
+    assert_eq!(catacombs.get_completions(7), 25);
+    assert_eq!(catacombs.get_total_completions(), 38);
+    assert_eq!(catacombs.get_fastest_time_s_plus(7), Some(312500));
+    assert_eq!(catacombs.get_best_score(7), Some(317));
+    assert_eq!(catacombs.get_watcher_kills(7), 25);
+    assert_eq!(dungeons.get_selected_class(), Some(DungeonClass::Mage));
+    assert!(dungeons.dungeon_journal.is_some());
+    assert_eq!(profile.get_highest_floor_completed(UUID, false), Some(7));
+    assert_eq!(profile.get_highest_floor_completed(UUID, true), Some(3));
+    assert!(profile.is_master_mode_unlocked(UUID));
+    let mage = profile
+        .get_dungeon_class(UUID, DungeonClass::Mage, false)
+        .unwrap();
+    assert_eq!(
+        profile.get_class_average(UUID, false),
+        mage.level as f64 / 5.0
+    );
+
+    // Farming 50/60, combat 22 and six other skills at 0
+    let breakdown = profile
+        .get_skill_breakdown(UUID, &Skill::AVERAGE_EXCLUDED)
+        .unwrap();
+    assert_eq!(breakdown.skills.len(), 8);
+    assert_eq!(breakdown.farming_cap_upgrade, 10);
+    assert_eq!(breakdown.total_exp, 55172425 + 1500000);
+    assert_eq!(breakdown.average, (50.0 + 22.0) / 8.0);
+    assert!(breakdown.average_with_progress > breakdown.average);
+    assert!(breakdown
+        .skills
+        .iter()
+        .all(|entry| entry.leveling.overflow_exp == 0));
+    assert_eq!(
+        profile.get_total_skill_exp(UUID, &[]),
+        Some(55172425 + 1500000 + 3000)
+    );
+
+    let pets = profile.get_pets(UUID).unwrap();
+    assert_eq!(pets.len(), 1);
+    assert_eq!(pets[0].name, "TIGER");
+    assert_eq!(pets[0].level, 100);
+    assert_eq!(pets[0].display_name, "Tiger");
+    assert_eq!(pets[0].stats["strength"], 15.0);
+    assert_eq!(profile.get_active_pet(UUID).unwrap().name, "TIGER");
+    assert_eq!(profile.get_pet_score(UUID), 6);
+    let missing = profile.get_missing_pets(UUID);
+    assert!(!missing[&Rarity::Legendary].contains(&"TIGER".to_string()));
+    assert!(missing[&Rarity::Legendary].contains(&"LION".to_string()));
+    // Pet rarities can come from other data, such as a newer list of pets
+    let pet_rarities = [
+        ("TIGER", (Rarity::Common, Rarity::Mythic)),
+        ("NEW_PET", (Rarity::Epic, Rarity::Legendary)),
+    ]
+    .into_iter()
+    .map(|(pet_type, rarities)| (pet_type.to_string(), rarities))
+    .collect();
+    let missing = profile.get_missing_pets_with(UUID, &pet_rarities);
+    assert_eq!(missing[&Rarity::Epic], vec!["NEW_PET"]);
+    assert_eq!(missing[&Rarity::Legendary], vec!["NEW_PET"]);
+    assert_eq!(missing[&Rarity::Mythic], vec!["TIGER"]);
+    assert!(!missing.contains_key(&Rarity::Common));
+    let summary = profile.get_pet_summary(UUID).unwrap();
+    assert_eq!((summary.total, summary.max_level), (1, 1));
+    assert_eq!(summary.by_rarity[&Rarity::Legendary], 1);
+
+    let member = profile.member(UUID).unwrap();
+    assert_eq!(member.coin_purse, 12345.5);
+    assert_eq!(member.first_join, Some(1600000000000));
+    assert_eq!(member.fairy_souls_collected, 200);
+    assert_eq!(member.skills.combat, Some(1500000.0));
+    assert_eq!(member.skills.social, Some(3000.0));
+    assert_eq!(member.slayer_bosses["zombie"].xp, 1000000.0);
+    assert_eq!(
+        member.jacob2.as_ref().unwrap().perks["farming_level_cap"],
+        10
+    );
+    assert_eq!(member.pets[0].pet_type, "TIGER");
+    assert!(member.pets[0].active);
+    let member_inventory = member
+        .inventories
+        .inv_contents
+        .unwrap()
+        .get_items()
+        .unwrap();
+    assert_eq!(member_inventory.len(), inventory.len());
+    assert_eq!(member.inventories.backpack_contents.len(), 1);
+    assert_eq!(member.crafted_generators.len(), 3);
+    assert_eq!(member.stats["kills"], 100.0);
+    assert_eq!(member.stats["kills_zombie"], 60.0);
+    assert_eq!(member.objectives["collect_log"].status, "COMPLETE");
+}
+
+#[test]
+fn legacy_layout() {
+    let profile = load("skyblock_profile_legacy");
+    assert_profile(&profile);
+    assert_eq!(profile.get_bank_account(UUID), None);
+    assert_eq!(profile.get_dungeon_secrets(UUID), None);
+    assert_eq!(profile.get_skyblock_level(UUID), None);
+}
+
+#[test]
+fn v2_layout() {
+    let profile = load("skyblock_profile_v2");
+    assert_profile(&profile);
+    assert_eq!(profile.get_bank_account(UUID), Some(5000.0));
+    assert_eq!(profile.get_dungeon_secrets(UUID), Some(1234));
+    assert_eq!(profile.get_skyblock_level(UUID), Some(150.5));
+
+    let member = profile.member(UUID).unwrap();
+    assert_eq!(member.bank_account, Some(5000.0));
+    assert_eq!(member.leveling.unwrap().experience, 15050.0);
+    // The raw member is kept in the layout returned by the API
+    assert!(member.raw.get("currencies").is_some());
+    assert!(member.raw.get("coin_purse").is_none());
+}
+
+#[test]
+fn mistyped_fields() {
+    let mut raw: serde_json::Value = load("skyblock_profile_v2");
+    let member = &mut raw["members"][UUID];
+    member["currencies"]["coin_purse"] = "lots".into();
+    member["player_data"]["experience"]["SKILL_MINING"] = serde_json::json!([1]);
+    member["slayer"]["slayer_bosses"]["zombie"]["boss_kills_tier_4"] = "150".into();
+    member["dungeons"]["dungeon_types"]["catacombs"]["highest_tier_completed"] = true.into();
+    member["dungeons"]["secrets"] = serde_json::json!({});
+    member["pets_data"]["pets"][0]["candyUsed"] = serde_json::Value::Null;
+    member["leveling"] = 5.into();
+
+    let profile: SkyblockProfile = serde_json::from_value(raw).unwrap();
+    let member = profile.member(UUID).unwrap();
+
+    // Only the mistyped fields fall back to their defaults
+    assert_eq!(member.coin_purse, 0.0);
+    assert_eq!(member.skills.mining, None);
+    assert_eq!(member.skills.combat, Some(1500000.0));
+    let zombie = &member.slayer_bosses["zombie"];
+    assert_eq!((zombie.xp, zombie.boss_kills_tier_4), (1000000.0, 0));
+    let dungeons = member.dungeons.as_ref().unwrap();
+    assert_eq!(dungeons.secrets, None);
+    let catacombs = dungeons.get_catacombs().unwrap();
+    assert_eq!(catacombs.highest_tier_completed, None);
+    assert_eq!(catacombs.get_completions(7), 25);
+    assert_eq!(member.pets[0].pet_type, "TIGER");
+    assert_eq!(member.pets[0].candy_used, 0);
+    assert!(member.leveling.is_none());
+    assert_eq!(member.fairy_souls_collected, 200);
+}
+
+#[test]
+fn skill_overflow() {
+    // 111,672,425 exp for level 60, then 7.6M for 61 and 8.2M for 62
+    let profile: SkyblockProfile = serde_json::from_value(serde_json::json!({
+        "profile_id": "4d5e6f7a8b9c4d0e8f1a2b3c4d5e6f70",
+        "members": { UUID: { "experience_skill_combat": 111_672_425 + 7_600_000 + 4_100_000 } }
+    }))
+    .unwrap();
+
+    let combat = profile.get_skill(UUID, Skill::Combat, false).unwrap();
+    assert_eq!(
+        (combat.level, combat.overflow_level, combat.overflow_exp),
+        (60, 0, 0)
+    );
+    assert_eq!(combat.exp_for_next, 0);
+
+    let combat = profile.get_skill(UUID, Skill::Combat, true).unwrap();
+    assert_eq!(combat.level, 60);
+    assert_eq!(combat.overflow_level, 1);
+    assert_eq!(combat.overflow_exp, 11_700_000);
+    assert_eq!(combat.current_exp, 4_100_000);
+    assert_eq!(combat.exp_for_next, 8_200_000);
+    assert_eq!(combat.get_progress_level(), 61.5);
+}
+
+#[test]
+fn slayer_overflow_exp_only() {
+    let profile: SkyblockProfile = serde_json::from_value(serde_json::json!({
+        "profile_id": "4d5e6f7a8b9c4d0e8f1a2b3c4d5e6f70",
+        "members": { UUID: { "slayer_bosses": { "zombie": { "xp": 3_500_000 } } } }
+    }))
+    .unwrap();
+
+    let zombie = profile.get_slayer(UUID, Slayer::Zombie, false).unwrap();
+    assert_eq!(
+        (zombie.level, zombie.overflow_level, zombie.overflow_exp),
+        (9, 0, 0)
+    );
+
+    // Slayers only report the exp past level 9
+    let zombie = profile.get_slayer(UUID, Slayer::Zombie, true).unwrap();
+    assert_eq!(
+        (zombie.level, zombie.overflow_level, zombie.overflow_exp),
+        (9, 0, 2_500_000)
+    );
+    assert_eq!(zombie.get_progress_level(), 9.0);
+}
+
+#[test]
+fn overflow_starts_at_max_level() {
+    // Alchemy caps at 50, so level 51 needs the level 50 exp plus the slope, not the table's 4.3M
+    let level_50: i64 = LevelingTables::default().get_skill_table(Skill::Alchemy)[..50]
+        .iter()
+        .sum();
+    let profile: SkyblockProfile = serde_json::from_value(serde_json::json!({
+        "profile_id": "4d5e6f7a8b9c4d0e8f1a2b3c4d5e6f70",
+        "members": { UUID: { "experience_skill_alchemy": level_50 + 4_500_000 } }
+    }))
+    .unwrap();
+
+    let alchemy = profile.get_skill(UUID, Skill::Alchemy, true).unwrap();
+    assert_eq!((alchemy.level, alchemy.max_level), (50, 50));
+    assert_eq!(alchemy.overflow_level, 0);
+    assert_eq!(alchemy.exp_for_next, 4_000_000 + 600_000);
+    assert_eq!(alchemy.overflow_exp, 4_500_000);
+}
+
+#[test]
+fn maxed_skill_breakdown() {
+    let level_60: i64 = LevelingTables::default().get_skill_table(Skill::Combat)[..60]
+        .iter()
+        .sum();
+    let level_50: i64 = LevelingTables::default().get_skill_table(Skill::Alchemy)[..50]
+        .iter()
+        .sum();
+    let profile: SkyblockProfile = serde_json::from_value(serde_json::json!({
+        "profile_id": "4d5e6f7a8b9c4d0e8f1a2b3c4d5e6f70",
+        "members": { UUID: {
+            "experience_skill_combat": level_60 + 50_000_000,
+            "experience_skill_alchemy": level_50 + 50_000_000,
+        } }
+    }))
+    .unwrap();
+    assert!(
+        profile
+            .get_skill(UUID, Skill::Combat, true)
+            .unwrap()
+            .overflow_level
+            > 0
+    );
+
+    let breakdown = profile.get_skill_breakdown(UUID, &[]).unwrap();
+    let count = breakdown.skills.len() as f64;
+    for entry in &breakdown.skills {
+        assert!(entry.leveling.get_progress_level() <= entry.leveling.max_level as f64);
+    }
+    assert_eq!(breakdown.average, (60.0 + 50.0) / count);
+    assert_eq!(breakdown.average_with_progress, breakdown.average);
+    assert_eq!(
+        profile.get_skill_average_with_progress(UUID, &[]),
+        Some((60.0 + 50.0) / count)
+    );
+}
+
+#[test]
+fn golden_dragon_levels() {
+    let golden_dragon = |exp: i64| {
+        PetStruct::from_raw(&serde_json::json!({
+            "type": "GOLDEN_DRAGON",
+            "tier": "LEGENDARY",
+            "exp": exp,
+        }))
+        .unwrap()
+    };
+
+    assert_eq!(golden_dragon(25_353_229).level, 99);
+    // Levels 101 and 102 take 0 and 5,555 exp, then each level takes 1,886,700
+    assert_eq!(golden_dragon(25_353_230 + 5_555).level, 102);
+    let level_150 = golden_dragon(25_358_785 + 48 * 1_886_700);
+    assert_eq!((level_150.level, level_150.exp_for_next), (150, 1_886_700));
+    assert_eq!(golden_dragon(210_255_384).level, 199);
+
+    let maxed = golden_dragon(210_255_385);
+    assert_eq!((maxed.level, maxed.max_level), (200, 200));
+    assert!(maxed.is_maxed());
+    assert_eq!(maxed.exp_for_next, 0);
+    assert_eq!(golden_dragon(300_000_000).level, 200);
+}
+
+#[test]
+fn tier_boost_rarity() {
+    let enderman = |tier: &str, held_item: Option<&str>| {
+        PetStruct::from_raw(&serde_json::json!({
+            "type": "ENDERMAN",
+            "tier": tier,
+            "exp": 18_608_500,
+            "heldItem": held_item,
+        }))
+        .unwrap()
+    };
+
+    // Epic level 100 exp isn't enough for a legendary pet
+    let epic = enderman("EPIC", None);
+    assert_eq!(
+        (epic.rarity, epic.effective_rarity),
+        (Rarity::Epic, Rarity::Epic)
+    );
+    assert_eq!(epic.level, 100);
+
+    let boosted = enderman("EPIC", Some("PET_ITEM_TIER_BOOST"));
+    assert_eq!(boosted.rarity, Rarity::Epic);
+    assert_eq!(boosted.effective_rarity, Rarity::Legendary);
+    assert_eq!(boosted.level, 96);
+    assert_eq!(boosted.held_item_name.as_deref(), Some("Tier Boost"));
+    assert_eq!(boosted.get_formatted_name(), "§7[Lvl 96] §6Enderman");
+
+    let mythic = enderman("MYTHIC", Some("PET_ITEM_TIER_BOOST"));
+    assert_eq!(mythic.effective_rarity, Rarity::Mythic);
+    assert_eq!(
+        enderman("LEGENDARY", Some("PET_ITEM_SPOOKY_CUPCAKE")).effective_rarity,
+        Rarity::Legendary
+    );
+}
+
+#[test]
+fn pet_stats_by_rarity() {
+    let tiger = |tier: &str, held_item: Option<&str>| {
+        PetStruct::from_raw(&serde_json::json!({
+            "type": "TIGER",
+            "tier": tier,
+            "exp": 25_353_230,
+            "heldItem": held_item,
+        }))
+        .unwrap()
+    };
+
+    let legendary = tiger("LEGENDARY", None);
+    assert_eq!(legendary.level, 100);
+    assert_eq!(legendary.stats["strength"], 15.0);
+    assert_eq!(legendary.stats["crit_damage"], 50.0);
+    assert_eq!(legendary.stats["ferocity"], 25.0);
+
+    let common = tiger("COMMON", None);
+    assert_eq!(common.level, 100);
+    assert_eq!(common.stats["strength"], 15.0);
+    assert_eq!(common.stats["crit_damage"], 10.0);
+    assert_eq!(common.stats["ferocity"], 10.0);
+
+    // A tier boost gives the stats of the boosted rarity
+    let boosted = tiger("COMMON", Some("PET_ITEM_TIER_BOOST"));
+    assert_eq!(boosted.stats["crit_damage"], 20.0);
+
+    assert_eq!(
+        PetStruct::get_stats("TIGER", Rarity::Rare, 50)["crit_damage"],
+        15.0
+    );
+    assert!(PetStruct::get_stats("ENDER_DRAGON", Rarity::Rare, 100).is_empty());
+    assert!(PetStruct::get_stats("UNKNOWN_PET", Rarity::Legendary, 100).is_empty());
+}
+
+#[test]
+fn collections() {
+    let profile: SkyblockProfile = load("skyblock_profile_v2");
+    let resource: SkyblockCollectionsResponse = load("skyblock_collections");
+    let collections = profile.get_collections(UUID, &resource).unwrap();
+    assert_eq!(collections.len(), 2);
+
+    // Tiers come from the coop total of 110
+    let wheat = &collections[0];
+    assert_eq!(
+        (wheat.category.as_str(), wheat.name.as_str()),
+        ("Farming", "Wheat")
+    );
+    assert_eq!((wheat.amount, wheat.coop_amount), (60, 110));
+    assert_eq!((wheat.tier, wheat.max_tier), (2, 3));
+    assert_eq!(wheat.amount_to_next_tier, Some(140));
+
+    let cobblestone = &collections[1];
+    assert_eq!(cobblestone.tier, 4);
+    assert!(cobblestone.is_maxed());
+    assert_eq!(cobblestone.amount_to_next_tier, None);
+}
+
+#[test]
+fn leveling_tables_from_resource() {
+    let resource: SkyblockSkillsResponse = load("skyblock_skills");
+    let tables = LevelingTables::from_skills_resource(&resource);
+    let defaults = LevelingTables::default();
+
+    assert_eq!(tables.get_skill_table(Skill::Combat), [50, 125, 200]);
+    assert_eq!(tables.get_skill_max_level(Skill::Combat), 3);
+    // The resource max includes the farming level cap upgrade
+    assert_eq!(tables.get_skill_max_level(Skill::Farming), 50);
+    assert_eq!(
+        tables.get_skill_table(Skill::Mining),
+        defaults.get_skill_table(Skill::Mining)
+    );
+    assert_eq!(tables.catacombs, defaults.catacombs);
+    assert_eq!(tables.get_slayer_max_level(Slayer::Vampire), 5);
+}
+
+#[test]
+fn pet_rarities_from_resource() {
+    let resource = serde_json::json!({
+        "TIGER": { "COMMON": {}, "LEGENDARY": {}, "EPIC": {} },
+        "NEW_PET": { "MYTHIC": {}, "EPIC": {}, "stats": {} },
+        "BROKEN_PET": [],
+    });
+    let tables = LevelingTables::default().with_pets_resource(&resource);
+    assert_eq!(tables.pet_rarities.len(), 2);
+    assert_eq!(
+        tables.pet_rarities["TIGER"],
+        (Rarity::Common, Rarity::Legendary)
+    );
+    assert_eq!(
+        tables.pet_rarities["NEW_PET"],
+        (Rarity::Epic, Rarity::Mythic)
+    );
+    assert_eq!(tables.skills, LevelingTables::default().skills);
+
+    let profile: SkyblockProfile = load("skyblock_profile_v2");
+    let missing = profile.get_missing_pets_with(UUID, &tables.pet_rarities);
+    assert_eq!(missing[&Rarity::Epic], vec!["NEW_PET"]);
+    assert!(!missing.contains_key(&Rarity::Common));
+}
@@ -0,0 +1,210 @@
+use rs_pixel::{
+    types::{dungeon_class::DungeonClass, skill::Skill, slayer::Slayer},
+    util::{
+        skyblock_profile::SkyblockProfile,
+        weight::{LILY_WEIGHT_V1, SENITHER_WEIGHT_V1},
+    },
+};
+use serde_json::{json, Value};
+
+const UUID: &str = "0123456789abcdef0123456789abcdef";
+
+/// Total exp for level 60 and level 50 skills and for Catacombs 50
+const SKILL_60_EXP: f64 = 111_672_425.0;
+const SKILL_50_EXP: f64 = 55_172_425.0;
+const CATACOMBS_50_EXP: f64 = 569_809_640.0;
+
+fn profile(member: Value) -> SkyblockProfile {
+    serde_json::from_value(json!({
+        "profile_id": "4d5e6f7a8b9c4d0e8f1a2b3c4d5e6f70",
+        "members": { UUID: member }
+    }))
+    .unwrap()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "expected {expected} but got {actual}"
+    );
+}
+
+/// FNV-1a hash of the constants, so changing one without bumping the version fails a test
+fn fingerprint(tables: &impl std::fmt::Debug) -> u64 {
+    format!("{tables:?}")
+        .bytes()
+        .fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+#[test]
+fn weight_versions() {
+    assert_eq!(
+        (SENITHER_WEIGHT_V1.version, fingerprint(&SENITHER_WEIGHT_V1)),
+        ("1", 10280236014972874590)
+    );
+    assert_eq!(
+        (LILY_WEIGHT_V1.version, fingerprint(&LILY_WEIGHT_V1)),
+        ("1", 9683635580956171383)
+    );
+}
+
+// Reference values are from the formulas in Senither's weight calculator
+
+#[test]
+fn senither_skill_weight() {
+    let weight = profile(json!({
+        "experience_skill_combat": SKILL_60_EXP,
+        "experience_skill_alchemy": SKILL_50_EXP + 10_000_000.0,
+        "experience_skill_fishing": 3_022_425,
+    }))
+    .get_senither_weight(UUID);
+
+    let combat = weight.skills[&Skill::Combat];
+    assert_close(combat.weight, 1500.0000000905231);
+    assert_eq!(combat.overflow, 0.0);
+
+    // Past the max level the weight is rounded and the rest is overflow
+    let alchemy = weight.skills[&Skill::Alchemy];
+    assert_eq!(alchemy.weight, 200.0);
+    assert_close(alchemy.overflow, 8.445321518039956);
+
+    // Level 25
+    let fishing = weight.skills[&Skill::Fishing];
+    assert_close(fishing.weight, 250_f64.powf(0.5 + 1.406418 + 0.25) / 1250.0);
+    assert_eq!(fishing.overflow, 0.0);
+
+    let overflowing = profile(json!({ "experience_skill_combat": SKILL_60_EXP + 100_000_000.0 }))
+        .get_senither_weight(UUID);
+    let combat = overflowing.skills[&Skill::Combat];
+    assert_eq!(combat.weight, 1500.0);
+    assert_close(combat.overflow, 300.1997039783195);
+    assert!(!overflowing.skills.contains_key(&Skill::Mining));
+}
+
+#[test]
+fn senither_slayer_weight() {
+    let weight = profile(json!({
+        "slayer_bosses": {
+            "zombie": { "xp": 500_000 },
+            "wolf": { "xp": 3_000_000 },
+        }
+    }))
+    .get_senither_weight(UUID);
+
+    let zombie = weight.slayers[&Slayer::Zombie];
+    assert_close(zombie.weight, 226.44927536231884);
+    assert_eq!(zombie.overflow, 0.0);
+
+    // Each million past the first uses a growing modifier
+    let wolf = weight.slayers[&Slayer::Wolf];
+    assert_close(wolf.weight, 509.683995922528);
+    assert_close(wolf.overflow, 477.9122875490908);
+    assert_close(
+        weight.get_slayers_weight().get_total(),
+        226.44927536231884 + 509.683995922528 + 477.9122875490908,
+    );
+}
+
+#[test]
+fn senither_dungeon_weight() {
+    let weight = profile(json!({
+        "dungeons": {
+            "dungeon_types": { "catacombs": { "experience": CATACOMBS_50_EXP } },
+            "player_classes": { "healer": { "experience": CATACOMBS_50_EXP } },
+        }
+    }))
+    .get_senither_weight(UUID);
+    assert_close(weight.catacombs.weight, 9500.000000852486);
+    assert_eq!(weight.catacombs.overflow, 0.0);
+    assert_close(
+        weight.classes[&DungeonClass::Healer].weight,
+        200.00000001794706,
+    );
+    assert!(!weight.classes.contains_key(&DungeonClass::Mage));
+
+    // Past level 50 the weight is floored and the rest is overflow
+    let weight = profile(json!({
+        "dungeons": {
+            "dungeon_types": { "catacombs": { "experience": CATACOMBS_50_EXP + 100_000_000.0 } },
+        }
+    }))
+    .get_senither_weight(UUID);
+    assert_eq!(weight.catacombs.weight, 9500.0);
+    assert_close(weight.catacombs.overflow, 343.6338511814723);
+    assert_close(
+        weight.get_dungeons_weight().get_total(),
+        9500.0 + 343.6338511814723,
+    );
+}
+
+// Reference values are worked out from the formulas documented on `LilyWeightTables`
+
+#[test]
+fn lily_skill_weight() {
+    let weight = profile(json!({
+        "experience_skill_combat": SKILL_60_EXP,
+        "experience_skill_alchemy": SKILL_50_EXP + 10_000_000.0,
+        "experience_skill_fishing": 3_022_425,
+    }))
+    .get_lily_weight(UUID);
+
+    // Skills without experience count towards the average of (60 + 50 + 25) / 8
+    let combat = weight.skills[&Skill::Combat];
+    assert_close(combat.weight, 89.17584138632192);
+    assert_eq!(combat.overflow, 0.0);
+
+    let alchemy = weight.skills[&Skill::Alchemy];
+    assert_close(alchemy.weight, 80.67890709819358);
+    assert_close(alchemy.overflow, 8.445321518039956);
+
+    assert_close(weight.skills[&Skill::Fishing].weight, 5.617205820648581);
+    assert!(!weight.skills.contains_key(&Skill::Mining));
+}
+
+#[test]
+fn lily_slayer_weight() {
+    let weight = profile(json!({
+        "slayer_bosses": {
+            "zombie": { "xp": 250_000 },
+            "wolf": { "xp": 3_000_000 },
+        }
+    }))
+    .get_lily_weight(UUID);
+
+    // 100k, then 97k, then 50k at 0.97 squared
+    let zombie = weight.slayers[&Slayer::Zombie];
+    assert_close(zombie.weight, 110.5276268115942);
+    assert_eq!(zombie.overflow, 0.0);
+    assert_close(weight.slayers[&Slayer::Wolf].weight, 1158.2968415330356);
+}
+
+#[test]
+fn lily_dungeon_weight() {
+    let weight = profile(json!({
+        "dungeons": {
+            "dungeon_types": {
+                "catacombs": {
+                    "experience": CATACOMBS_50_EXP,
+                    "tier_completions": { "1": 500, "7": 2000 },
+                },
+                "master_catacombs": { "tier_completions": { "7": 100 } },
+            },
+            "player_classes": { "healer": { "experience": CATACOMBS_50_EXP } },
+        }
+    }))
+    .get_lily_weight(UUID);
+
+    assert_close(weight.catacombs.weight, 9500.000000852486);
+    assert!(weight.classes.is_empty());
+    // Floor 7 completions past the cap of 1000 count logarithmically
+    assert_close(
+        weight.completions.weight,
+        649.1585514370124 + 25.31645569620253,
+    );
+    assert_close(
+        weight.get_dungeons_weight().get_total(),
+        9500.000000852486 + 674.475007133215,
+    );
+}